@@ -188,12 +188,17 @@ export default function ElectionDetailPage() {
       const [adminRegistryPda] = getAdminRegistryPda(program.programId);
       const [adminPda] = getAdminPda(publicKey, program.programId);
 
+      // The candidate's rent goes back to whoever paid for it
+      // @ts-ignore
+      const candidateAccount = await program.account.candidate.fetch(candidatePubkey);
+
       // @ts-ignore
       const tx = await program.methods.removeCandidate().accounts({
         adminRegistry: adminRegistryPda,
         adminAccount: adminPda,
         election: electionPubkey,
         candidate: candidatePubkey,
+        payer: candidateAccount.payer,
         authority: publicKey,
       }).rpc();
 
@@ -5,6 +5,7 @@ import { useEffect, useState } from 'react';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useProgram } from '@/hooks/useProgram';
 import { PublicKey } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
 import { getAdminRegistryPda, getAdminPda, getVoterRegistrationPda } from '@/lib/helpers';
 import { logger } from '@/lib/logger';
 import { parseElectionStatus, VoterRegistrationType, parseVoterRegistrationType, RegistrationStatus } from '@/lib/types';
@@ -182,7 +183,7 @@ export default function ElectionVotersPage() {
 
       // @ts-ignore
       const tx = await program.methods
-        .approveVoterRegistration()
+        .approveVoterRegistration(new BN(1), null) // weight, district_id
         .accounts({
           adminRegistry: adminRegistryPda,
           adminAccount: adminPda,
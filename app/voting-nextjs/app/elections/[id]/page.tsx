@@ -18,7 +18,14 @@ import {
   formatElectionTime,
   getTimeRemaining,
 } from '@/lib/election-utils';
-import { getVoterRegistrationPda, getVoteRecordPda, getAdminRegistryPda } from '@/lib/helpers';
+import {
+  getVoterRegistrationPda,
+  getVoteRecordPda,
+  getAdminRegistryPda,
+  getVoteEscrowPda,
+  getDistrictPda,
+  getElectionVaultPda,
+} from '@/lib/helpers';
 import { logger } from '@/lib/logger';
 import { Button } from '@/components/ui/button';
 import { AppLayout } from '@/components/shared/AppLayout';
@@ -46,6 +53,7 @@ interface CandidateData {
   name: string;
   description: string;
   imageUrl: string;
+  districtId: number | null;
   voteCount: number;
 }
 
@@ -131,6 +139,7 @@ export default function VoterElectionDetailPage() {
         name: acc.account.name,
         description: acc.account.description,
         imageUrl: acc.account.imageUrl,
+        districtId: acc.account.districtId ?? null,
         voteCount: Number(acc.account.voteCount?.toString?.() ?? acc.account.voteCount),
       }));
       candidatesData.sort((a, b) => a.candidateId - b.candidateId);
@@ -212,13 +221,28 @@ export default function VoterElectionDetailPage() {
         voterRegistrationAccount = PublicKey.default;
       }
 
+      // Voters can only choose candidates in their own district, so the
+      // candidate's district is the one whose tally changes. Elections without
+      // districts ignore the account, but it must still be writable.
+      const candidate = candidates.find((c) => c.publicKey === selectedCandidate);
+      const [districtPda] = getDistrictPda(electionPubkey, candidate?.districtId ?? 0, program.programId);
+
+      // The vote escrow is only read in token-weighted elections; a funded
+      // election vault refunds the vote rent to the payer
+      const [voteEscrowPda] = getVoteEscrowPda(publicKey, program.programId);
+      const [electionVaultPda] = getElectionVaultPda(electionPubkey, program.programId);
+
       const accounts: any = {
         adminRegistry: adminRegistryPda,
         election: electionPubkey,
         candidate: candidatePubkey,
         voterRegistration: voterRegistrationAccount,
+        voteEscrow: voteEscrowPda,
+        district: districtPda,
+        electionVault: electionVaultPda,
         voteRecord: voteRecordPda,
         voter: publicKey,
+        payer: publicKey,
         systemProgram: SystemProgram.programId,
       };
 
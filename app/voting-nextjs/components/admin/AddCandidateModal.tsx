@@ -9,7 +9,7 @@ import {
   getAdminPda,
   getCandidatePda
 } from '@/lib/helpers';
-import { MAX_NAME_LENGTH, MAX_DESCRIPTION_LENGTH, MAX_IMAGE_URL_LENGTH } from '@/lib/constants';
+import { MAX_NAME_LENGTH, MAX_CANDIDATE_DESCRIPTION_LENGTH, MAX_IMAGE_URL_LENGTH } from '@/lib/constants';
 import { logger } from '@/lib/logger';
 import {
   Dialog,
@@ -44,6 +44,7 @@ export function AddCandidateModal({
   const [candidateName, setCandidateName] = useState('');
   const [candidateDescription, setCandidateDescription] = useState('');
   const [candidateImageUrl, setCandidateImageUrl] = useState('');
+  const [candidateWallet, setCandidateWallet] = useState('');
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState('');
   const [success, setSuccess] = useState(false);
@@ -60,8 +61,8 @@ export function AddCandidateModal({
       return;
     }
 
-    if (candidateDescription && candidateDescription.length > MAX_DESCRIPTION_LENGTH) {
-      setError(`Description too long (max ${MAX_DESCRIPTION_LENGTH} characters)`);
+    if (candidateDescription && candidateDescription.length > MAX_CANDIDATE_DESCRIPTION_LENGTH) {
+      setError(`Description too long (max ${MAX_CANDIDATE_DESCRIPTION_LENGTH} characters)`);
       return;
     }
 
@@ -70,6 +71,17 @@ export function AddCandidateModal({
       return;
     }
 
+    // The candidate's own wallet lets them withdraw during the election
+    let walletPubkey: PublicKey | null = null;
+    if (candidateWallet.trim()) {
+      try {
+        walletPubkey = new PublicKey(candidateWallet.trim());
+      } catch {
+        setError('Please enter a valid candidate wallet address');
+        return;
+      }
+    }
+
     try {
       setLoading(true);
       setError('');
@@ -85,10 +97,10 @@ export function AddCandidateModal({
         electionId: electionPubkey.toString(),
       });
 
-      // Fetch election to get candidate_count
+      // Fetch election to get next_candidate_id (ids are not reused after removals)
       // @ts-ignore
       const electionAccount = await program.account.election.fetch(electionPubkey);
-      const candidateId = electionAccount.candidateCount;
+      const candidateId = electionAccount.nextCandidateId;
 
       // Derive candidate PDA
       const [candidatePda] = getCandidatePda(
@@ -103,7 +115,10 @@ export function AddCandidateModal({
         .addCandidate(
           candidateName,
           candidateDescription || '',
-          candidateImageUrl || ''
+          candidateImageUrl || '',
+          null, // district_id
+          null, // contest_id
+          walletPubkey
         )
         .accountsStrict({
           adminRegistry: adminRegistryPda,
@@ -124,6 +139,7 @@ export function AddCandidateModal({
         setCandidateName('');
         setCandidateDescription('');
         setCandidateImageUrl('');
+        setCandidateWallet('');
         setSuccess(false);
         onSuccess();
       }, 1500);
@@ -141,7 +157,7 @@ export function AddCandidateModal({
       } else if (error.message?.includes('NameTooLong')) {
         errorMsg = `Candidate name is too long (max ${MAX_NAME_LENGTH} characters)`;
       } else if (error.message?.includes('DescriptionTooLong')) {
-        errorMsg = `Description is too long (max ${MAX_DESCRIPTION_LENGTH} characters)`;
+        errorMsg = `Description is too long (max ${MAX_CANDIDATE_DESCRIPTION_LENGTH} characters)`;
       } else if (error.message?.includes('ImageUrlTooLong')) {
         errorMsg = `Image URL is too long (max ${MAX_IMAGE_URL_LENGTH} characters)`;
       } else if (error.message?.includes('insufficient')) {
@@ -163,6 +179,7 @@ export function AddCandidateModal({
       setCandidateName('');
       setCandidateDescription('');
       setCandidateImageUrl('');
+      setCandidateWallet('');
       setError('');
       setSuccess(false);
       onClose();
@@ -217,13 +234,13 @@ export function AddCandidateModal({
                   value={candidateDescription}
                   onChange={(e) => setCandidateDescription(e.target.value)}
                   placeholder="Provide details about the candidate's background, qualifications, or platform..."
-                  maxLength={MAX_DESCRIPTION_LENGTH}
+                  maxLength={MAX_CANDIDATE_DESCRIPTION_LENGTH}
                   rows={4}
                   className="bg-gray-800 border-gray-700 text-white placeholder:text-gray-500 resize-none"
                   disabled={loading}
                 />
                 <p className="text-xs text-gray-500">
-                  {candidateDescription.length}/{MAX_DESCRIPTION_LENGTH} characters
+                  {candidateDescription.length}/{MAX_CANDIDATE_DESCRIPTION_LENGTH} characters
                 </p>
               </div>
 
@@ -246,6 +263,21 @@ export function AddCandidateModal({
                 </p>
               </div>
 
+              {/* Candidate Wallet Field */}
+              <div className="space-y-2">
+                <Label htmlFor="wallet" className="text-white">
+                  Candidate Wallet <span className="text-gray-500 text-xs">(Optional)</span>
+                </Label>
+                <Input
+                  id="wallet"
+                  value={candidateWallet}
+                  onChange={(e) => setCandidateWallet(e.target.value)}
+                  placeholder="Wallet address allowed to withdraw the candidacy"
+                  className="bg-gray-800 border-gray-700 text-white placeholder:text-gray-500"
+                  disabled={loading}
+                />
+              </div>
+
               {/* Error Message */}
               {error && (
                 <div className="flex items-start gap-2 p-3 bg-red-500/10 border border-red-500/20 rounded-lg">
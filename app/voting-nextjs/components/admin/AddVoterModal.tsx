@@ -4,6 +4,7 @@ import { useState } from 'react';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useProgram } from '@/hooks/useProgram';
 import { PublicKey, SystemProgram } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
 import {
   getAdminRegistryPda,
   getAdminPda,
@@ -107,7 +108,7 @@ export function AddVoterModal({
       // This allows super admin or admins with can_manage_voters permission to add voters directly
       // @ts-ignore
       const tx = await program.methods
-        .addVoterDirectly()
+        .addVoterDirectly(new BN(1), null) // weight, district_id
         .accountsStrict({
           adminRegistry: adminRegistryPda,
           election: electionPubkey,
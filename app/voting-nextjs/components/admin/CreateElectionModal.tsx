@@ -258,7 +258,8 @@ export function CreateElectionModal({ open, onClose, onSuccess }: CreateElection
           electionDescription || '',
           new BN(Math.floor(startTime)),
           new BN(Math.floor(endTime)),
-          voterRegType
+          voterRegType,
+          { standard: {} } // one vote per voter
         )
         .accountsStrict({
           adminRegistry: adminRegistryPda,
@@ -3,7 +3,7 @@
 import { useState } from 'react';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useProgram } from '@/hooks/useProgram';
-import { PublicKey } from '@solana/web3.js';
+import { PublicKey, SYSVAR_SLOT_HASHES_PUBKEY } from '@solana/web3.js';
 import { getAdminRegistryPda, getAdminPda } from '@/lib/helpers';
 import {
   canStartElection,
@@ -48,6 +48,7 @@ export function ElectionLifecycleControls({
           adminRegistry: adminRegistryPda,
           adminAccount: adminPda,
           election: electionPubkey,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
           authority: publicKey,
         })
         .rpc();
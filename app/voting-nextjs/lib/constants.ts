@@ -20,6 +20,9 @@ export const ELECTION_SEED = 'election';
 export const CANDIDATE_SEED = 'candidate';
 export const VOTER_REGISTRATION_SEED = 'voter_reg';
 export const VOTE_RECORD_SEED = 'vote';
+export const VOTE_ESCROW_SEED = 'vote_escrow';
+export const DISTRICT_SEED = 'district';
+export const ELECTION_VAULT_SEED = 'election_vault';
 
 // ============================================
 // STRING LENGTH LIMITS (from constants.rs)
@@ -29,3 +32,4 @@ export const MAX_TITLE_LENGTH = 100;
 export const MAX_DESCRIPTION_LENGTH = 500;
 export const MAX_NAME_LENGTH = 50;
 export const MAX_IMAGE_URL_LENGTH = 200;
+export const MAX_CANDIDATE_DESCRIPTION_LENGTH = 200;
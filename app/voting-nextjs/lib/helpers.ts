@@ -7,6 +7,9 @@ import {
   CANDIDATE_SEED,
   VOTER_REGISTRATION_SEED,
   VOTE_RECORD_SEED,
+  VOTE_ESCROW_SEED,
+  DISTRICT_SEED,
+  ELECTION_VAULT_SEED,
   PROGRAM_ID,
 } from './constants';
 
@@ -148,6 +151,53 @@ export function getVoteRecordPda(
   );
 }
 
+/**
+ * Get Vote Escrow PDA
+ * Seeds: ["vote_escrow", owner_pubkey]
+ */
+export function getVoteEscrowPda(
+  ownerPubkey: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(VOTE_ESCROW_SEED), ownerPubkey.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Get District PDA
+ * Seeds: ["district", election_pubkey, district_id (u16, little-endian)]
+ */
+export function getDistrictPda(
+  electionPubkey: PublicKey,
+  districtId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(DISTRICT_SEED),
+      electionPubkey.toBuffer(),
+      new BN(districtId).toArrayLike(Buffer, 'le', 2) // u16 = 2 bytes
+    ],
+    programId
+  );
+}
+
+/**
+ * Get Election Vault PDA
+ * Seeds: ["election_vault", election_pubkey]
+ */
+export function getElectionVaultPda(
+  electionPubkey: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(ELECTION_VAULT_SEED), electionPubkey.toBuffer()],
+    programId
+  );
+}
+
 // ============================================
 // ADMIN PERMISSIONS PRESETS
 // ============================================
@@ -5,6 +5,8 @@ pub const MAX_TITLE_LENGTH: usize = 100;
 pub const MAX_DESCRIPTION_LENGTH: usize = 500;
 pub const MAX_NAME_LENGTH: usize = 50;
 pub const MAX_IMAGE_URL_LENGTH: usize = 200;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const MAX_CANDIDATE_DESCRIPTION_LENGTH: usize = 200; // Longer text belongs in the metadata document
 
 // PDA seeds
 pub const ADMIN_REGISTRY_SEED: &[u8] = b"admin_registry";
@@ -13,5 +15,55 @@ pub const ELECTION_SEED: &[u8] = b"election";
 pub const CANDIDATE_SEED: &[u8] = b"candidate";
 pub const VOTER_REGISTRATION_SEED: &[u8] = b"voter_reg";
 pub const VOTE_RECORD_SEED: &[u8] = b"vote";
+pub const VOTE_ESCROW_SEED: &[u8] = b"vote_escrow";
+pub const ESCROW_VAULT_SEED: &[u8] = b"escrow_vault";
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+pub const DISTRICT_SEED: &[u8] = b"district";
+pub const CONTEST_SEED: &[u8] = b"contest";
+pub const CONTEST_VOTE_RECORD_SEED: &[u8] = b"contest_vote";
+pub const ELECTION_VAULT_SEED: &[u8] = b"election_vault";
+pub const SERIES_SEED: &[u8] = b"series";
+pub const SERIES_EDITION_SEED: &[u8] = b"series_edition";
+pub const ARCHIVED_ELECTION_SEED: &[u8] = b"archived_election";
+pub const NOMINATION_SEED: &[u8] = b"nomination";
+pub const PARTY_SEED: &[u8] = b"party";
+pub const PARTY_VOTE_RECORD_SEED: &[u8] = b"party_vote";
+pub const CANDIDATE_DEPOSIT_SEED: &[u8] = b"candidate_deposit";
+pub const DEPOSIT_VAULT_SEED: &[u8] = b"deposit_vault";
+
+// Vote escrow lock limits (seconds)
+pub const MIN_LOCK_DURATION: i64 = 7 * 24 * 60 * 60;        // 1 week
+pub const MAX_LOCK_DURATION: i64 = 4 * 365 * 24 * 60 * 60;  // 4 years
+
+// Default cap on candidates per election
+pub const DEFAULT_MAX_CANDIDATES: u32 = 100;
+
+// Default cap on how far an election's end time may be extended (seconds)
+pub const DEFAULT_MAX_TOTAL_EXTENSION: i64 = 3 * 24 * 60 * 60; // 3 days
+
+// Time a finished election's accounts are kept before they may be closed
+pub const ARCHIVE_RETENTION_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Depth of the Merkle tree of archived vote records (up to 2^20 records)
+pub const VOTE_RECORD_TREE_DEPTH: usize = 20;
+
+// Most candidate tallies an archive can hold (keeps the account under the
+// 10 KiB allocation limit)
+pub const MAX_ARCHIVED_CANDIDATES: u32 = 256;
+
+// Most parties an election may have, so every party result fits in the archive
+pub const MAX_PARTIES: u16 = 64;
+
+// Largest bounty an election may pay per lifecycle crank (0.01 SOL)
+pub const MAX_CRANK_BOUNTY: u64 = 10_000_000;
+
+// Most candidates a voter may pick in a single contest
+pub const MAX_CONTEST_CHOICES: u8 = 10;
+
+// Most seats an election may fill from party lists
+pub const MAX_PARTY_LIST_SEATS: u16 = 500;
+
+// Longest delegator -> delegate chain counted towards a vote
+pub const MAX_DELEGATION_DEPTH: u8 = 3;
 
 pub const SUPER_ADMIN: Pubkey = pubkey!("LssxRdEeDV3fLd4y4m3akAPfz3HApTBw9yh7TJvFFhP");
\ No newline at end of file
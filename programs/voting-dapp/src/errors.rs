@@ -92,4 +92,216 @@ pub enum VotingError {
     
     #[msg("Invalid input")]
     InvalidInput,
+
+    // Vote escrow errors
+    #[msg("Invalid lock duration")]
+    InvalidLockDuration,
+    
+    #[msg("Escrow is still locked")]
+    EscrowStillLocked,
+    
+    #[msg("Invalid vote escrow")]
+    InvalidVoteEscrow,
+    
+    #[msg("Escrow unlocks before the election ends")]
+    EscrowUnlocksBeforeElectionEnds,
+    
+    #[msg("No voting power")]
+    NoVotingPower,
+    
+    // Delegation errors
+    #[msg("Cannot delegate to yourself")]
+    SelfDelegation,
+    
+    #[msg("Invalid delegation")]
+    InvalidDelegation,
+    
+    #[msg("Delegation would create a cycle")]
+    DelegationCycle,
+    
+    #[msg("Delegation chain too deep")]
+    DelegationDepthExceeded,
+    
+    #[msg("Vote was not cast by a delegate")]
+    VoteNotDelegated,
+    
+    // Vote change errors
+    #[msg("Vote carries delegated power and cannot be retracted")]
+    DelegatedVoteCannotBeRetracted,
+    
+    // Voter weight errors
+    #[msg("Invalid voter weight")]
+    InvalidVoterWeight,
+    
+    // District errors
+    #[msg("Districts require a whitelist election")]
+    DistrictsRequireWhitelist,
+    
+    #[msg("Invalid district")]
+    InvalidDistrict,
+    
+    #[msg("Candidate is outside the voter's district")]
+    CandidateOutsideDistrict,
+    
+    // Contest errors
+    #[msg("Invalid contest")]
+    InvalidContest,
+    
+    #[msg("Invalid ballot selection")]
+    InvalidBallotSelection,
+    
+    // Election vault errors
+    #[msg("Insufficient election vault funds")]
+    InsufficientVaultFunds,
+    
+    // Lifecycle crank errors
+    #[msg("No lifecycle transition is due")]
+    NothingToCrank,
+    
+    #[msg("Crank bounty too large")]
+    CrankBountyTooLarge,
+    
+    // Election extension errors
+    #[msg("New end time must be later than the current end time")]
+    ExtensionNotLater,
+    
+    #[msg("Election extension limit exceeded")]
+    ExtensionLimitExceeded,
+    
+    // Election pause errors
+    #[msg("Election is paused")]
+    ElectionPaused,
+    
+    #[msg("Election is not paused")]
+    ElectionNotPaused,
+    
+    // Registration window errors
+    #[msg("Voter registration has not opened yet")]
+    RegistrationNotOpen,
+    
+    #[msg("Voter registration is closed")]
+    RegistrationClosed,
+    
+    #[msg("Voter roll is frozen")]
+    VoterRollFrozen,
+    
+    // Series errors
+    #[msg("Invalid series edition")]
+    InvalidSeriesEdition,
+    
+    #[msg("Edition results already recorded")]
+    EditionResultsAlreadyRecorded,
+    
+    #[msg("Every candidate of the election must be provided")]
+    IncompleteCandidateList,
+    
+    // Primary errors
+    #[msg("Invalid primary election link")]
+    InvalidPrimaryLink,
+    
+    #[msg("Primary candidates already promoted")]
+    CandidatesAlreadyPromoted,
+    
+    #[msg("Linked primaries have not been promoted yet")]
+    PrimariesPending,
+    
+    // Archive errors
+    #[msg("Election is not finalized or cancelled")]
+    ElectionNotClosed,
+    
+    #[msg("Retention period has not passed")]
+    RetentionPeriodActive,
+    
+    #[msg("Invalid rent recipient")]
+    InvalidRentRecipient,
+    
+    #[msg("Too many candidates to archive")]
+    TooManyCandidatesToArchive,
+    
+    #[msg("Archive is full")]
+    ArchiveFull,
+    
+    // Nomination errors
+    #[msg("Nominator must sign the nomination")]
+    NominatorSignatureMissing,
+    
+    #[msg("Nomination is not pending")]
+    NominationNotPending,
+    
+    #[msg("Nomination can only be closed once rejected or once its election is closed")]
+    NominationNotRejected,
+    
+    // Candidate status errors
+    #[msg("Candidate is not active")]
+    CandidateInactive,
+    
+    #[msg("Votes for this candidate were voided")]
+    CandidateVotesVoided,
+    
+    // Party list errors
+    #[msg("Election has no party-list ballot")]
+    PartyListNotEnabled,
+    
+    #[msg("Invalid party")]
+    InvalidParty,
+    
+    #[msg("Candidate is already on a party list")]
+    CandidateAlreadyInParty,
+    
+    #[msg("All parties of the election must be provided")]
+    IncompletePartyList,
+    
+    #[msg("Party seats have already been assigned")]
+    SeatsAlreadyAssigned,
+    
+    // Candidate metadata errors
+    #[msg("Metadata URI too long (max 200 characters)")]
+    MetadataUriTooLong,
+    
+    #[msg("Metadata hash is required with a metadata URI")]
+    InvalidMetadataHash,
+    
+    // Ballot order errors
+    #[msg("SlotHashes sysvar has no entries")]
+    BallotSeedUnavailable,
+    
+    // Candidate deposit errors
+    #[msg("Election does not take deposits in this currency")]
+    DepositNotRequired,
+    
+    #[msg("Deposits have already been paid")]
+    DepositsAlreadyPaid,
+    
+    #[msg("Candidate deposits are outstanding")]
+    DepositsOutstanding,
+    
+    #[msg("Deposit cannot be settled yet")]
+    DepositNotSettleable,
+    
+    #[msg("Candidate deposit has not been settled")]
+    DepositNotSettled,
+    
+    #[msg("Invalid deposit treasury")]
+    InvalidTreasury,
+    
+    // Election deletion errors
+    #[msg("Unknown or foreign account passed for deletion")]
+    InvalidElectionAccount,
+    
+    #[msg("Every account of the election must be closed with it")]
+    ElectionAccountsRemaining,
+    
+    #[msg("Series editions cannot be deleted; cancel them instead")]
+    SeriesEditionNotDeletable,
+    
+    // Deposit settlement errors
+    #[msg("SOL deposit is below the rent-exempt minimum")]
+    DepositBelowRentExempt,
+    
+    // Party archive errors
+    #[msg("Party limit reached")]
+    PartyLimitReached,
+    
+    #[msg("Too many parties to archive")]
+    TooManyPartiesToArchive,
 }
\ No newline at end of file
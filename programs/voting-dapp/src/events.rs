@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+use crate::state::ExtensionReason;
+
+#[event]
+pub struct ElectionExtended {
+    pub election: Pubkey,            // Extended election
+    pub extended_by: Pubkey,         // Admin who extended it
+    pub previous_end_time: i64,      // End time before the extension
+    pub new_end_time: i64,           // End time after the extension
+    pub extension_count: u16,        // Extensions so far, including this one
+    pub total_extension: i64,        // Seconds added so far, including this one
+    pub reason: ExtensionReason,     // Why voting was extended
+    pub timestamp: i64,              // Extension timestamp
+}
@@ -0,0 +1,885 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+use crate::utils::close_account;
+
+// ARCHIVE ELECTION (summary written before a finished election is closed)
+#[derive(Accounts)]
+pub struct ArchiveElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.is_closed() @ VotingError::ElectionNotClosed,
+        constraint = election.candidate_count <= MAX_ARCHIVED_CANDIDATES @ VotingError::TooManyCandidatesToArchive,
+        constraint = election.party_count <= MAX_PARTIES @ VotingError::TooManyPartiesToArchive
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ArchivedElection::size(election.candidate_count, election.party_count),
+        seeds = [ARCHIVED_ELECTION_SEED, election.key().as_ref()],
+        bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn archive_election(ctx: Context<ArchiveElection>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let election = &ctx.accounts.election;
+    let clock = Clock::get()?;
+
+    let closed_at = election.closed_at.ok_or(VotingError::ElectionNotClosed)?;
+    require!(
+        clock.unix_timestamp >= closed_at.saturating_add(ARCHIVE_RETENTION_PERIOD),
+        VotingError::RetentionPeriodActive
+    );
+
+    let archive = &mut ctx.accounts.archived_election;
+
+    archive.election = election.key();
+    archive.election_id = election.election_id;
+    archive.title = election.title.clone();
+    archive.status = election.status;
+    archive.total_votes = election.total_votes;
+    archive.total_ballots = election.total_ballots;
+    archive.candidate_count = election.candidate_count;
+    archive.closed_at = closed_at;
+    archive.archived_at = clock.unix_timestamp;
+    archive.election_closed = false;
+    archive.candidate_tallies = Vec::with_capacity(election.candidate_count as usize);
+    archive.vote_record_count = 0;
+    archive.vote_record_frontier = [[0u8; 32]; VOTE_RECORD_TREE_DEPTH];
+    archive.vault_closed_to = None;
+    archive.party_votes = election.party_votes;
+    archive.party_count = election.party_count;
+    archive.party_results = Vec::with_capacity(election.party_count as usize);
+    archive.bump = ctx.bumps.archived_election;
+    archive.update_vote_records_root();
+
+    msg!("Election archived");
+    msg!("Election ID: {}", archive.election_id);
+    msg!("Total votes: {}", archive.total_votes);
+    msg!("Total ballots: {}", archive.total_ballots);
+
+    Ok(())
+}
+
+// CLOSE VOTE RECORDS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [vote record, rent recipient], where the
+// recipient must be the payer stored on the record (the vault's authority
+// for vault-sponsored records once the vault is closed).
+#[derive(Accounts)]
+pub struct CloseVoteRecords<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_vote_records<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseVoteRecords<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let archive = &mut ctx.accounts.archived_election;
+    let election_key = archive.election;
+    let (vault_key, _) = Pubkey::find_program_address(
+        &[ELECTION_VAULT_SEED, election_key.as_ref()],
+        ctx.program_id
+    );
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let record_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            record_info.owner == ctx.program_id,
+            VotingError::InvalidInput
+        );
+        let record = {
+            let data = record_info.try_borrow_data()?;
+            VoteRecord::try_deserialize(&mut &data[..])?
+        };
+        let (expected_record_pda, _) = Pubkey::find_program_address(
+            &[
+                VOTE_RECORD_SEED,
+                election_key.as_ref(),
+                record.voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            record_info.key() == expected_record_pda,
+            VotingError::InvalidInput
+        );
+        require!(
+            recipient_info.key() == archive.rent_recipient(record.payer, &vault_key),
+            VotingError::InvalidRentRecipient
+        );
+
+        require!(
+            archive.append_vote_record(record.archive_leaf()),
+            VotingError::ArchiveFull
+        );
+        close_account(record_info, recipient_info)?;
+    }
+
+    archive.update_vote_records_root();
+
+    msg!("Vote records closed: {}", ctx.remaining_accounts.len() / 2);
+    msg!("Archived vote records: {}", archive.vote_record_count);
+
+    Ok(())
+}
+
+// CLOSE VOTER REGISTRATIONS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [voter registration, rent recipient].
+#[derive(Accounts)]
+pub struct CloseVoterRegistrations<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_voter_registrations<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseVoterRegistrations<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let election_key = ctx.accounts.archived_election.election;
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let registration_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            registration_info.owner == ctx.program_id,
+            VotingError::VoterNotRegistered
+        );
+        let registration = {
+            let data = registration_info.try_borrow_data()?;
+            VoterRegistration::try_deserialize(&mut &data[..])?
+        };
+        let (expected_registration_pda, _) = Pubkey::find_program_address(
+            &[
+                VOTER_REGISTRATION_SEED,
+                election_key.as_ref(),
+                registration.voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            registration_info.key() == expected_registration_pda,
+            VotingError::VoterNotRegistered
+        );
+        require!(
+            recipient_info.key() == registration.payer,
+            VotingError::InvalidRentRecipient
+        );
+
+        close_account(registration_info, recipient_info)?;
+    }
+
+    msg!("Voter registrations closed: {}", ctx.remaining_accounts.len() / 2);
+
+    Ok(())
+}
+
+// CLOSE CANDIDATES (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [candidate, rent recipient]. Each
+// candidate's final tally is copied into the archive.
+#[derive(Accounts)]
+pub struct CloseCandidates<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_candidates<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseCandidates<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let archive = &mut ctx.accounts.archived_election;
+    let election_key = archive.election;
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let candidate_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            candidate_info.owner == ctx.program_id,
+            VotingError::InvalidCandidate
+        );
+        let candidate = {
+            let data = candidate_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+        let (expected_candidate_pda, _) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                election_key.as_ref(),
+                candidate.candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            candidate_info.key() == expected_candidate_pda,
+            VotingError::InvalidCandidate
+        );
+        require!(
+            recipient_info.key() == candidate.payer,
+            VotingError::InvalidRentRecipient
+        );
+        require!(
+            !candidate.deposit_paid,
+            VotingError::DepositNotSettled
+        );
+        require!(
+            !archive.all_candidates_closed(),
+            VotingError::ArchiveFull
+        );
+
+        archive.candidate_tallies.push(CandidateTally {
+            candidate_id: candidate.candidate_id,
+            contest_id: candidate.contest_id,
+            vote_count: candidate.vote_count,
+        });
+        close_account(candidate_info, recipient_info)?;
+    }
+
+    msg!("Candidates closed: {}", ctx.remaining_accounts.len() / 2);
+    msg!("Archived candidates: {}", archive.candidate_tallies.len());
+
+    Ok(())
+}
+
+// CLOSE CONTEST VOTE RECORDS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [contest vote record, rent recipient] for
+// ballots in contest `contest_id`, where the recipient is determined as for
+// vote records. The contest account itself may already be closed.
+#[derive(Accounts)]
+pub struct CloseContestVoteRecords<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_contest_vote_records<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseContestVoteRecords<'info>>,
+    contest_id: u16,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let archive = &ctx.accounts.archived_election;
+    let election_key = archive.election;
+    let (vault_key, _) = Pubkey::find_program_address(
+        &[ELECTION_VAULT_SEED, election_key.as_ref()],
+        ctx.program_id
+    );
+    let (contest_key, _) = Pubkey::find_program_address(
+        &[
+            CONTEST_SEED,
+            election_key.as_ref(),
+            contest_id.to_le_bytes().as_ref()
+        ],
+        ctx.program_id
+    );
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let record_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            record_info.owner == ctx.program_id,
+            VotingError::InvalidContest
+        );
+        let record = {
+            let data = record_info.try_borrow_data()?;
+            ContestVoteRecord::try_deserialize(&mut &data[..])?
+        };
+        let (expected_record_pda, _) = Pubkey::find_program_address(
+            &[
+                CONTEST_VOTE_RECORD_SEED,
+                contest_key.as_ref(),
+                record.voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            record_info.key() == expected_record_pda && record.contest == contest_key,
+            VotingError::InvalidContest
+        );
+        require!(
+            recipient_info.key() == archive.rent_recipient(record.payer, &vault_key),
+            VotingError::InvalidRentRecipient
+        );
+
+        close_account(record_info, recipient_info)?;
+    }
+
+    msg!("Contest vote records closed: {}", ctx.remaining_accounts.len() / 2);
+    msg!("Contest ID: {}", contest_id);
+
+    Ok(())
+}
+
+// CLOSE CONTESTS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [contest, rent recipient]. Contest
+// results stay available through the archived candidate tallies.
+#[derive(Accounts)]
+pub struct CloseContests<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_contests<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseContests<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let election_key = ctx.accounts.archived_election.election;
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let contest_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            contest_info.owner == ctx.program_id,
+            VotingError::InvalidContest
+        );
+        let contest = {
+            let data = contest_info.try_borrow_data()?;
+            Contest::try_deserialize(&mut &data[..])?
+        };
+        let (expected_contest_pda, _) = Pubkey::find_program_address(
+            &[
+                CONTEST_SEED,
+                election_key.as_ref(),
+                contest.contest_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            contest_info.key() == expected_contest_pda,
+            VotingError::InvalidContest
+        );
+        require!(
+            recipient_info.key() == contest.payer,
+            VotingError::InvalidRentRecipient
+        );
+
+        close_account(contest_info, recipient_info)?;
+    }
+
+    msg!("Contests closed: {}", ctx.remaining_accounts.len() / 2);
+
+    Ok(())
+}
+
+// CLOSE DISTRICTS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [district, rent recipient].
+#[derive(Accounts)]
+pub struct CloseDistricts<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_districts<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseDistricts<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let election_key = ctx.accounts.archived_election.election;
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let district_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            district_info.owner == ctx.program_id,
+            VotingError::InvalidDistrict
+        );
+        let district = {
+            let data = district_info.try_borrow_data()?;
+            District::try_deserialize(&mut &data[..])?
+        };
+        let (expected_district_pda, _) = Pubkey::find_program_address(
+            &[
+                DISTRICT_SEED,
+                election_key.as_ref(),
+                district.district_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            district_info.key() == expected_district_pda,
+            VotingError::InvalidDistrict
+        );
+        require!(
+            recipient_info.key() == district.payer,
+            VotingError::InvalidRentRecipient
+        );
+
+        close_account(district_info, recipient_info)?;
+    }
+
+    msg!("Districts closed: {}", ctx.remaining_accounts.len() / 2);
+
+    Ok(())
+}
+
+// CLOSE PARTY VOTE RECORDS (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [party vote record, rent recipient], where
+// the recipient is determined as for vote records.
+#[derive(Accounts)]
+pub struct ClosePartyVoteRecords<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_party_vote_records<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClosePartyVoteRecords<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let archive = &ctx.accounts.archived_election;
+    let election_key = archive.election;
+    let (vault_key, _) = Pubkey::find_program_address(
+        &[ELECTION_VAULT_SEED, election_key.as_ref()],
+        ctx.program_id
+    );
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let record_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            record_info.owner == ctx.program_id,
+            VotingError::InvalidParty
+        );
+        let record = {
+            let data = record_info.try_borrow_data()?;
+            PartyVoteRecord::try_deserialize(&mut &data[..])?
+        };
+        let (expected_record_pda, _) = Pubkey::find_program_address(
+            &[
+                PARTY_VOTE_RECORD_SEED,
+                election_key.as_ref(),
+                record.voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            record_info.key() == expected_record_pda,
+            VotingError::InvalidParty
+        );
+        require!(
+            recipient_info.key() == archive.rent_recipient(record.payer, &vault_key),
+            VotingError::InvalidRentRecipient
+        );
+
+        close_account(record_info, recipient_info)?;
+    }
+
+    msg!("Party vote records closed: {}", ctx.remaining_accounts.len() / 2);
+
+    Ok(())
+}
+
+// CLOSE PARTIES (permissionless, after archival)
+//
+// Remaining accounts hold pairs of [party, rent recipient]. Each party's
+// result is copied into the archive.
+#[derive(Accounts)]
+pub struct CloseParties<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ARCHIVED_ELECTION_SEED, archived_election.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+}
+
+pub fn close_parties<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseParties<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::InvalidInput
+    );
+
+    let archive = &mut ctx.accounts.archived_election;
+    let election_key = archive.election;
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let party_info = &pair[0];
+        let recipient_info = &pair[1];
+
+        require!(
+            party_info.owner == ctx.program_id,
+            VotingError::InvalidParty
+        );
+        let party = {
+            let data = party_info.try_borrow_data()?;
+            Party::try_deserialize(&mut &data[..])?
+        };
+        let (expected_party_pda, _) = Pubkey::find_program_address(
+            &[
+                PARTY_SEED,
+                election_key.as_ref(),
+                party.party_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            party_info.key() == expected_party_pda,
+            VotingError::InvalidParty
+        );
+        require!(
+            recipient_info.key() == party.payer,
+            VotingError::InvalidRentRecipient
+        );
+        require!(
+            !archive.all_parties_closed(),
+            VotingError::ArchiveFull
+        );
+
+        archive.party_results.push(PartyResult {
+            party_id: party.party_id,
+            vote_count: party.vote_count,
+            seats_won: party.seats_won,
+            seats_filled: party.seats_filled,
+        });
+        close_account(party_info, recipient_info)?;
+    }
+
+    msg!("Parties closed: {}", ctx.remaining_accounts.len() / 2);
+    msg!("Archived parties: {}", archive.party_results.len());
+
+    Ok(())
+}
+
+// CLOSE ARCHIVED ELECTION (permissionless, once every candidate and party is archived)
+#[derive(Accounts)]
+pub struct CloseArchivedElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ARCHIVED_ELECTION_SEED, election.key().as_ref()],
+        bump = archived_election.bump,
+        constraint = archived_election.all_candidates_closed() @ VotingError::IncompleteCandidateList,
+        constraint = archived_election.all_parties_closed() @ VotingError::IncompletePartyList,
+        constraint = !archived_election.election_closed @ VotingError::InvalidInput
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump
+    )]
+    pub election: Account<'info, Election>,
+
+    /// CHECK: Receives the election's rent; must be the stored payer.
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == election.payer @ VotingError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn close_archived_election(ctx: Context<CloseArchivedElection>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let archive = &mut ctx.accounts.archived_election;
+    archive.election_closed = true;
+
+    msg!("Election account closed");
+    msg!("Election ID: {}", archive.election_id);
+    msg!("Rent returned to: {}", ctx.accounts.rent_recipient.key());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::*;
+    use crate::{accounts, instruction};
+
+    // Finalized election 0 with one party, archived with its vault closed
+    fn archived(ledger: &mut TestLedger, vault_authority: Pubkey) -> (Pubkey, Pubkey) {
+        setup_admin(ledger);
+
+        let (election_key, mut election) = draft_election(0);
+        election.status = ElectionStatus::Finalized;
+        election.party_count = 1;
+        ledger.set_account(election_key, &election, Election::SIZE);
+
+        let (archive_key, bump) = pda(&[ARCHIVED_ELECTION_SEED, election_key.as_ref()]);
+        let archive = ArchivedElection {
+            election: election_key,
+            election_id: 0,
+            title: election.title.clone(),
+            status: ElectionStatus::Finalized,
+            total_votes: 0,
+            total_ballots: 0,
+            candidate_count: 0,
+            closed_at: 0,
+            archived_at: 0,
+            election_closed: false,
+            candidate_tallies: vec![],
+            vote_record_count: 0,
+            vote_records_root: [0u8; 32],
+            vote_record_frontier: [[0u8; 32]; VOTE_RECORD_TREE_DEPTH],
+            vault_closed_to: Some(vault_authority),
+            party_votes: 7,
+            party_count: 1,
+            party_results: vec![],
+            bump,
+        };
+        ledger.set_account(archive_key, &archive, ArchivedElection::size(0, 1));
+        (election_key, archive_key)
+    }
+
+    fn close_election(
+        ledger: &mut TestLedger,
+        election: Pubkey,
+        archived_election: Pubkey,
+    ) -> std::result::Result<(), ProgramError> {
+        ledger.process(
+            accounts::CloseArchivedElection {
+                admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                archived_election,
+                election,
+                rent_recipient: SUPER_ADMIN,
+            },
+            instruction::CloseArchivedElection {},
+            &[],
+        )
+    }
+
+    #[test]
+    fn parties_are_archived_before_the_election_closes() {
+        let mut ledger = TestLedger::new(1_000);
+        let vault_authority = Pubkey::new_unique();
+        let (election_key, archive_key) = archived(&mut ledger, vault_authority);
+
+        let (party_key, party_bump) =
+            pda(&[PARTY_SEED, election_key.as_ref(), &0u16.to_le_bytes()]);
+        let party = Party {
+            election: election_key,
+            party_id: 0,
+            name: "Party 0".to_string(),
+            list_size: 2,
+            active_list_size: 2,
+            vote_count: 7,
+            seats_won: 2,
+            seats_filled: 1,
+            seats_assigned: true,
+            payer: SUPER_ADMIN,
+            bump: party_bump,
+        };
+        ledger.set_account(party_key, &party, Party::SIZE);
+
+        // A party-list ballot the election vault paid for
+        let voter = Pubkey::new_unique();
+        let (record_key, record_bump) =
+            pda(&[PARTY_VOTE_RECORD_SEED, election_key.as_ref(), voter.as_ref()]);
+        let record = PartyVoteRecord {
+            election: election_key,
+            voter,
+            party: party_key,
+            weight: 7,
+            payer: pda(&[ELECTION_VAULT_SEED, election_key.as_ref()]).0,
+            voted_at: 0,
+            bump: record_bump,
+        };
+        ledger.set_account(record_key, &record, PartyVoteRecord::SIZE);
+
+        let close_records = |ledger: &mut TestLedger, recipient: Pubkey| {
+            ledger.process(
+                accounts::ClosePartyVoteRecords {
+                    admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                    archived_election: archive_key,
+                },
+                instruction::ClosePartyVoteRecords {},
+                &[writable(record_key), writable(recipient)],
+            )
+        };
+        assert_eq!(
+            close_records(&mut ledger, voter),
+            Err(error(VotingError::InvalidRentRecipient))
+        );
+        let record_rent = ledger.lamports(&record_key);
+        close_records(&mut ledger, vault_authority).unwrap();
+        assert!(!ledger.exists(&record_key));
+        assert_eq!(ledger.lamports(&vault_authority), record_rent);
+
+        assert_eq!(
+            close_election(&mut ledger, election_key, archive_key),
+            Err(error(VotingError::IncompletePartyList))
+        );
+
+        let party_rent = ledger.lamports(&party_key);
+        let balance = ledger.lamports(&SUPER_ADMIN);
+        ledger
+            .process(
+                accounts::CloseParties {
+                    admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                    archived_election: archive_key,
+                },
+                instruction::CloseParties {},
+                &[writable(party_key), writable(SUPER_ADMIN)],
+            )
+            .unwrap();
+        assert!(!ledger.exists(&party_key));
+        assert_eq!(ledger.lamports(&SUPER_ADMIN), balance + party_rent);
+
+        let archive = ledger.get::<ArchivedElection>(&archive_key);
+        assert!(
+            archive.party_results
+                == vec![PartyResult {
+                    party_id: 0,
+                    vote_count: 7,
+                    seats_won: 2,
+                    seats_filled: 1,
+                }]
+        );
+
+        close_election(&mut ledger, election_key, archive_key).unwrap();
+        assert!(!ledger.exists(&election_key));
+    }
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::VotingError;
+use crate::instructions::district::update_district_tally;
 use crate::state::*;
+use crate::utils::write_account;
 
 // ADD CANDIDATE
 #[derive(Accounts)]
@@ -25,7 +27,8 @@ pub struct AddCandidate<'info> {
         mut,
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
         bump = election.bump,
-        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.can_add_candidate() @ VotingError::CandidateLimitReached
     )]
     pub election: Account<'info, Election>,
     
@@ -36,7 +39,7 @@ pub struct AddCandidate<'info> {
         seeds = [
             CANDIDATE_SEED,
             election.key().as_ref(),
-            election.candidate_count.to_le_bytes().as_ref()
+            election.next_candidate_id.to_le_bytes().as_ref()
         ],
         bump
     )]
@@ -53,9 +56,73 @@ pub fn add_candidate(
     name: String,
     description: String,
     image_url: String,
+    district_id: Option<u16>,
+    contest_id: Option<u16>,
+    wallet: Option<Pubkey>,
 ) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
 
+    validate_candidate_details(&name, &description, &image_url)?;
+
+    // Validate district (required when the election has districts)
+    require!(
+        ctx.accounts.election.is_valid_district(district_id),
+        VotingError::InvalidDistrict
+    );
+
+    // Validate contest (None = the election's main race)
+    require!(
+        ctx.accounts.election.is_valid_contest(contest_id),
+        VotingError::InvalidContest
+    );
+    
+    let candidate = &mut ctx.accounts.candidate;
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+    
+    candidate.election = election.key();
+    candidate.candidate_id = election.next_candidate_id;
+    candidate.name = name;
+    candidate.description = description;
+    candidate.image_url = image_url;
+    candidate.metadata_uri = None;
+    candidate.metadata_hash = None;
+    candidate.district_id = district_id;
+    candidate.contest_id = contest_id;
+    candidate.party_id = None;
+    candidate.list_position = 0;
+    candidate.source_candidate = None;
+    candidate.wallet = wallet;
+    candidate.vote_count = 0;
+    candidate.elected = false;
+    candidate.deposit_paid = false;
+    candidate.status = CandidateStatus::Active;
+    candidate.status_changed_at = None;
+    candidate.voided_votes = 0;
+    candidate.added_by = ctx.accounts.authority.key();
+    candidate.added_at = clock.unix_timestamp;
+    candidate.last_updated_at = clock.unix_timestamp;
+    candidate.payer = ctx.accounts.authority.key();
+    candidate.bump = ctx.bumps.candidate;
+
+    // IDs are never reused, so removed candidates leave their seed unused
+    election.next_candidate_id = election
+        .next_candidate_id
+        .checked_add(1)
+        .ok_or(VotingError::CandidateLimitReached)?;
+    // Use saturating_add for production safety (prevents overflow panic)
+    election.candidate_count = election.candidate_count.saturating_add(1);
+    
+    msg!("Candidate added");
+    msg!("Election: {}", election.title);
+    msg!("Candidate: {}", candidate.name);
+    msg!("Candidate ID: {}", candidate.candidate_id);
+    
+    Ok(())
+}
+
+// Validation shared by add_candidate and update_candidate
+pub(crate) fn validate_candidate_details(name: &str, description: &str, image_url: &str) -> Result<()> {
     // Validate name (required, non-empty)
     require!(
         !name.trim().is_empty(),
@@ -72,7 +139,7 @@ pub fn add_candidate(
         VotingError::InvalidInput
     );
     require!(
-        description.len() <= MAX_DESCRIPTION_LENGTH,
+        description.len() <= MAX_CANDIDATE_DESCRIPTION_LENGTH,
         VotingError::DescriptionTooLong
     );
 
@@ -81,32 +148,120 @@ pub fn add_candidate(
         image_url.len() <= MAX_IMAGE_URL_LENGTH,
         VotingError::ImageUrlTooLong
     );
+
+    Ok(())
+}
+
+// UPDATE CANDIDATE (Draft only)
+#[derive(Accounts)]
+pub struct UpdateCandidate<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump
+    )]
+    pub candidate: Account<'info, Candidate>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn update_candidate(
+    ctx: Context<UpdateCandidate>,
+    name: Option<String>,
+    description: Option<String>,
+    image_url: Option<String>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
     
     let candidate = &mut ctx.accounts.candidate;
-    let election = &mut ctx.accounts.election;
     let clock = Clock::get()?;
     
-    candidate.election = election.key();
-    candidate.candidate_id = election.candidate_count;
+    let name = name.unwrap_or_else(|| candidate.name.clone());
+    let description = description.unwrap_or_else(|| candidate.description.clone());
+    let image_url = image_url.unwrap_or_else(|| candidate.image_url.clone());
+    
+    validate_candidate_details(&name, &description, &image_url)?;
+    
     candidate.name = name;
     candidate.description = description;
     candidate.image_url = image_url;
-    candidate.vote_count = 0;
-    candidate.added_by = ctx.accounts.authority.key();
-    candidate.added_at = clock.unix_timestamp;
-    candidate.bump = ctx.bumps.candidate;
-
-    // Use saturating_add for production safety (prevents overflow panic)
-    election.candidate_count = election.candidate_count.saturating_add(1);
+    candidate.last_updated_at = clock.unix_timestamp;
     
-    msg!("Candidate added");
-    msg!("Election: {}", election.title);
+    msg!("Candidate updated");
+    msg!("Election: {}", ctx.accounts.election.title);
     msg!("Candidate: {}", candidate.name);
     msg!("Candidate ID: {}", candidate.candidate_id);
     
     Ok(())
 }
 
+// SET CANDIDATE METADATA (Draft only)
+//
+// Points the candidate at an off-chain JSON document following
+// candidate-metadata.schema.json. `metadata_hash` is the sha256 of the
+// document's bytes; an empty URI clears the metadata.
+pub fn set_candidate_metadata(
+    ctx: Context<UpdateCandidate>,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    require!(
+        metadata_uri.len() <= MAX_METADATA_URI_LENGTH,
+        VotingError::MetadataUriTooLong
+    );
+    
+    let candidate = &mut ctx.accounts.candidate;
+    
+    if metadata_uri.trim().is_empty() {
+        candidate.metadata_uri = None;
+        candidate.metadata_hash = None;
+    } else {
+        require!(
+            metadata_hash != [0u8; 32],
+            VotingError::InvalidMetadataHash
+        );
+        candidate.metadata_uri = Some(metadata_uri);
+        candidate.metadata_hash = Some(metadata_hash);
+    }
+    candidate.last_updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Candidate metadata updated");
+    msg!("Candidate: {}", candidate.name);
+    match &candidate.metadata_uri {
+        Some(uri) => msg!("Metadata URI: {}", uri),
+        None => msg!("Metadata cleared"),
+    }
+    
+    Ok(())
+}
+
 // REMOVE CANDIDATE
 #[derive(Accounts)]
 pub struct RemoveCandidate<'info> {
@@ -134,17 +289,25 @@ pub struct RemoveCandidate<'info> {
     
     #[account(
         mut,
-        close = authority,
+        close = payer,
         seeds = [
             CANDIDATE_SEED,
             election.key().as_ref(),
             candidate.candidate_id.to_le_bytes().as_ref()
         ],
-        bump = candidate.bump
+        bump = candidate.bump,
+        // Party lists must stay complete for seat assignment
+        constraint = candidate.party_id.is_none() @ VotingError::CandidateAlreadyInParty
     )]
     pub candidate: Account<'info, Candidate>,
     
-    #[account(mut)]
+    /// CHECK: Receives the candidate's rent; must be the stored payer.
+    #[account(
+        mut,
+        constraint = payer.key() == candidate.payer @ VotingError::InvalidRentRecipient
+    )]
+    pub payer: UncheckedAccount<'info>,
+    
     pub authority: Signer<'info>,
 }
 
@@ -155,10 +318,235 @@ pub fn remove_candidate(ctx: Context<RemoveCandidate>) -> Result<()> {
     let candidate = &ctx.accounts.candidate;
     
     election.candidate_count = election.candidate_count.saturating_sub(1);
+    // The deposit stays escrowed until settled as a refund
+    if candidate.deposit_paid {
+        election.deposited_candidates = election.deposited_candidates.saturating_sub(1);
+    }
     
     msg!("Candidate removed");
     msg!("Election: {}", election.title);
     msg!("Candidate: {}", candidate.name);
     
+    Ok(())
+}
+
+// WITHDRAW CANDIDATE (signed by the candidate's wallet, Active elections only;
+// Draft candidates are taken off the ballot with remove_candidate)
+#[derive(Accounts)]
+pub struct WithdrawCandidate<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.is_active() @ VotingError::ElectionNotActive
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = candidate.wallet == Some(wallet.key()) @ VotingError::Unauthorized,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
+    )]
+    pub candidate: Account<'info, Candidate>,
+    
+    /// CHECK: Optional district. For districted candidates, seeds validated in withdraw_candidate function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional contest. For contest candidates, seeds validated in withdraw_candidate function.
+    #[account(mut)]
+    pub contest: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional party. For party-list candidates, seeds validated in withdraw_candidate function.
+    #[account(mut)]
+    pub party: UncheckedAccount<'info>,
+    
+    pub wallet: Signer<'info>,
+}
+
+pub fn withdraw_candidate(ctx: Context<WithdrawCandidate>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    deactivate_candidate(
+        &mut ctx.accounts.election,
+        &mut ctx.accounts.candidate,
+        &ctx.accounts.district,
+        &ctx.accounts.contest,
+        &ctx.accounts.party,
+        CandidateStatus::Withdrawn,
+        ctx.program_id,
+    )?;
+    
+    msg!("Candidate withdrawn");
+    msg!("Election: {}", ctx.accounts.election.title);
+    msg!("Candidate: {}", ctx.accounts.candidate.name);
+    
+    Ok(())
+}
+
+// DISQUALIFY CANDIDATE (admin, Active elections only)
+#[derive(Accounts)]
+pub struct DisqualifyCandidate<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.is_active() @ VotingError::ElectionNotActive
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
+    )]
+    pub candidate: Account<'info, Candidate>,
+    
+    /// CHECK: Optional district. For districted candidates, seeds validated in disqualify_candidate function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional contest. For contest candidates, seeds validated in disqualify_candidate function.
+    #[account(mut)]
+    pub contest: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional party. For party-list candidates, seeds validated in disqualify_candidate function.
+    #[account(mut)]
+    pub party: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn disqualify_candidate(ctx: Context<DisqualifyCandidate>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    deactivate_candidate(
+        &mut ctx.accounts.election,
+        &mut ctx.accounts.candidate,
+        &ctx.accounts.district,
+        &ctx.accounts.contest,
+        &ctx.accounts.party,
+        CandidateStatus::Disqualified,
+        ctx.program_id,
+    )?;
+    
+    msg!("Candidate disqualified");
+    msg!("Election: {}", ctx.accounts.election.title);
+    msg!("Candidate: {}", ctx.accounts.candidate.name);
+    msg!("Disqualified by: {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
+
+// Marks the candidate inactive and takes a party-list candidate off the
+// party's active list. Under a voiding policy the candidate's votes are taken
+// off the election, district and contest tallies.
+fn deactivate_candidate(
+    election: &mut Election,
+    candidate: &mut Candidate,
+    district_info: &AccountInfo,
+    contest_info: &AccountInfo,
+    party_info: &AccountInfo,
+    status: CandidateStatus,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    candidate.status = status;
+    candidate.status_changed_at = Some(clock.unix_timestamp);
+    
+    if let Some(party_id) = candidate.party_id {
+        let (expected_party_pda, _) = Pubkey::find_program_address(
+            &[
+                PARTY_SEED,
+                candidate.election.as_ref(),
+                party_id.to_le_bytes().as_ref()
+            ],
+            program_id
+        );
+        require!(
+            party_info.key() == expected_party_pda && party_info.owner == program_id,
+            VotingError::InvalidParty
+        );
+        let mut party = {
+            let data = party_info.try_borrow_data()?;
+            Party::try_deserialize(&mut &data[..])?
+        };
+        party.active_list_size = party.active_list_size.saturating_sub(1);
+        write_account(&party, party_info)?;
+    }
+    
+    if !election.withdrawal_policy.voids_votes() {
+        return Ok(());
+    }
+    
+    let voided = candidate.vote_count;
+    match candidate.contest_id {
+        None => {
+            election.total_votes = election.total_votes.saturating_sub(voided);
+            update_district_tally(
+                district_info,
+                &candidate.election,
+                candidate.district_id,
+                program_id,
+                |district| district.total_votes = district.total_votes.saturating_sub(voided),
+            )?;
+        }
+        Some(contest_id) => {
+            let (expected_contest_pda, _) = Pubkey::find_program_address(
+                &[
+                    CONTEST_SEED,
+                    candidate.election.as_ref(),
+                    contest_id.to_le_bytes().as_ref()
+                ],
+                program_id
+            );
+            require!(
+                contest_info.key() == expected_contest_pda && contest_info.owner == program_id,
+                VotingError::InvalidContest
+            );
+            let mut contest = {
+                let data = contest_info.try_borrow_data()?;
+                Contest::try_deserialize(&mut &data[..])?
+            };
+            contest.total_votes = contest.total_votes.saturating_sub(voided);
+            write_account(&contest, contest_info)?;
+        }
+    }
+    
+    candidate.voided_votes = voided;
+    candidate.vote_count = 0;
+    
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::VotingError;
+use crate::instructions::delegation::apply_delegations;
+use crate::instructions::district::update_district_tally;
+use crate::instructions::vault::{is_election_vault, reimburse_rent};
 use crate::state::*;
 
 // CAST VOTE
@@ -16,7 +19,8 @@ pub struct CastVote<'info> {
         mut,
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
         bump = election.bump,
-        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
     )]
     pub election: Account<'info, Election>,
     
@@ -28,7 +32,9 @@ pub struct CastVote<'info> {
             candidate.candidate_id.to_le_bytes().as_ref()
         ],
         bump = candidate.bump,
-        constraint = candidate.election == election.key() @ VotingError::InvalidCandidate
+        constraint = candidate.election == election.key() @ VotingError::InvalidCandidate,
+        constraint = candidate.contest_id.is_none() @ VotingError::InvalidCandidate,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
     )]
     pub candidate: Account<'info, Candidate>,
     
@@ -37,9 +43,23 @@ pub struct CastVote<'info> {
     /// CHECK: Optional voter registration. For Whitelist elections, seeds/bump/status validated in cast_vote function.
     pub voter_registration: UncheckedAccount<'info>,
     
+    // Voter's vote escrow (only for VoteEscrow-weighted elections)
+    /// CHECK: Optional vote escrow. For VoteEscrow elections, seeds/owner/mint validated in cast_vote function.
+    pub vote_escrow: UncheckedAccount<'info>,
+    
+    // Candidate's district tally (only for elections with districts)
+    /// CHECK: Optional district. For districted elections, seeds validated in cast_vote function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+    
+    // Election vault refunding the vote rent to the payer (optional)
+    /// CHECK: Optional election vault. Seeds/owner validated in cast_vote function.
+    #[account(mut)]
+    pub election_vault: UncheckedAccount<'info>,
+    
     #[account(
         init,
-        payer = voter,
+        payer = payer,
         space = VoteRecord::SIZE,
         seeds = [
             VOTE_RECORD_SEED,
@@ -50,13 +70,16 @@ pub struct CastVote<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
     
-    #[account(mut)]
     pub voter: Signer<'info>,
     
+    // Pays the vote rent: the voter, a relayer or the organization
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
+pub fn cast_vote<'info>(ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
     
     let election = &mut ctx.accounts.election;
@@ -64,32 +87,345 @@ pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
     
-    // Check if election time is valid
+    // Enforce the voting window
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
     require!(
-        election.status == ElectionStatus::Active,
+        election.can_vote(clock.unix_timestamp),
         VotingError::ElectionNotActive
     );
     
+    let (weight, district_id) = voting_power(
+        election,
+        &ctx.accounts.voter.key(),
+        &ctx.accounts.voter_registration,
+        &ctx.accounts.vote_escrow,
+        ctx.program_id,
+        clock.unix_timestamp,
+    )?;
+    
+    // Voters may only choose candidates standing in their own district
+    require!(
+        candidate.district_id == district_id,
+        VotingError::CandidateOutsideDistrict
+    );
+    
+    // The payer fronts the rent; a passed election vault refunds it
+    let sponsored = is_election_vault(&ctx.accounts.election_vault, &election.key(), ctx.program_id);
+    let rent_payer = if sponsored {
+        ctx.accounts.election_vault.key()
+    } else {
+        ctx.accounts.payer.key()
+    };
+    
+    // Record the vote
+    vote_record.election = election.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.candidate = candidate.key();
+    vote_record.weight = weight;
+    vote_record.cast_by = ctx.accounts.voter.key();
+    vote_record.delegated_weight = 0;
+    vote_record.payer = rent_payer;
+    vote_record.voted_at = clock.unix_timestamp;
+    vote_record.revision = 0;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    // Add the power of delegators who have not voted themselves
+    let (delegated_weight, delegator_count) = apply_delegations(
+        election,
+        vote_record,
+        ctx.remaining_accounts,
+        &ctx.accounts.payer.to_account_info(),
+        rent_payer,
+        &ctx.accounts.system_program.to_account_info(),
+        district_id,
+    )?;
+    vote_record.delegated_weight = delegated_weight;
+    
+    if sponsored {
+        reimburse_rent(
+            &ctx.accounts.election_vault,
+            &ctx.accounts.payer.to_account_info(),
+            VoteRecord::SIZE,
+            1 + delegator_count as u64,
+        )?;
+    }
+
+    // Update vote counts with overflow protection
+    let total_weight = weight.saturating_add(delegated_weight);
+    let ballots = 1 + delegator_count as u64;
+    candidate.vote_count = candidate.vote_count.saturating_add(total_weight);
+    election.total_votes = election.total_votes.saturating_add(total_weight);
+    election.total_ballots = election.total_ballots.saturating_add(ballots);
+    
+    update_district_tally(
+        &ctx.accounts.district,
+        &election.key(),
+        district_id,
+        ctx.program_id,
+        |district| {
+            district.total_votes = district.total_votes.saturating_add(total_weight);
+            district.total_ballots = district.total_ballots.saturating_add(ballots);
+        },
+    )?;
+    
+    msg!("Vote cast successfully");
+    msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("Election: {}", election.title);
+    msg!("Candidate: {}", candidate.name);
+    msg!("Vote weight: {}", weight);
+    msg!("Delegators counted: {}", delegator_count);
+    msg!("Candidate vote count: {}", candidate.vote_count);
+    msg!("Total election votes: {}", election.total_votes);
+    msg!("Total ballots: {}", election.total_ballots);
+    
+    Ok(())
+}
+
+// CHANGE VOTE
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(
+        mut,
+        seeds = [
+            VOTE_RECORD_SEED,
+            election.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = !vote_record.is_delegated() @ VotingError::InvalidDelegation
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        mut,
+        constraint = previous_candidate.key() == vote_record.candidate @ VotingError::InvalidCandidate
+    )]
+    pub previous_candidate: Account<'info, Candidate>,
+    
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = candidate.election == election.key() @ VotingError::InvalidCandidate,
+        constraint = candidate.contest_id.is_none() @ VotingError::InvalidCandidate,
+        constraint = candidate.key() != previous_candidate.key() @ VotingError::InvalidCandidate,
+        constraint = candidate.district_id == previous_candidate.district_id @ VotingError::CandidateOutsideDistrict,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
+    )]
+    pub candidate: Account<'info, Candidate>,
+    
+    /// CHECK: Optional district. For districted elections, seeds validated in change_vote function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+    
+    pub voter: Signer<'info>,
+}
+
+pub fn change_vote(ctx: Context<ChangeVote>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+    
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    
+    // Delegated power follows the delegate's choice
+    let weight = vote_record.weight.saturating_add(vote_record.delegated_weight);
+    
+    let previous_candidate = &mut ctx.accounts.previous_candidate;
+    if election.votes_voided_for(previous_candidate) {
+        // The vote was taken off the tallies when the candidate left;
+        // recasting counts it again
+        require!(
+            election.withdrawal_policy == WithdrawalPolicy::AllowRecast,
+            VotingError::CandidateVotesVoided
+        );
+        election.total_votes = election.total_votes.saturating_add(weight);
+        update_district_tally(
+            &ctx.accounts.district,
+            &election.key(),
+            previous_candidate.district_id,
+            ctx.program_id,
+            |district| district.total_votes = district.total_votes.saturating_add(weight),
+        )?;
+    } else {
+        previous_candidate.vote_count = previous_candidate.vote_count.saturating_sub(weight);
+    }
+    
+    let candidate = &mut ctx.accounts.candidate;
+    candidate.vote_count = candidate.vote_count.saturating_add(weight);
+    
+    vote_record.candidate = candidate.key();
+    vote_record.voted_at = clock.unix_timestamp;
+    vote_record.revision = vote_record.revision.saturating_add(1);
+    
+    msg!("Vote changed");
+    msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("From: {}", previous_candidate.name);
+    msg!("To: {}", candidate.name);
+    msg!("Revision: {}", vote_record.revision);
+    
+    Ok(())
+}
+
+// RETRACT VOTE
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [
+            VOTE_RECORD_SEED,
+            election.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = !vote_record.is_delegated() @ VotingError::InvalidDelegation,
+        constraint = vote_record.delegated_weight == 0 @ VotingError::DelegatedVoteCannotBeRetracted
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        mut,
+        constraint = candidate.key() == vote_record.candidate @ VotingError::InvalidCandidate
+    )]
+    pub candidate: Account<'info, Candidate>,
+    
+    /// CHECK: Optional district. For districted elections, seeds validated in retract_vote function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the rent refund; must be whoever paid it
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == vote_record.payer @ VotingError::Unauthorized
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+    
+    pub voter: Signer<'info>,
+}
+
+pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let candidate = &mut ctx.accounts.candidate;
+    let weight = ctx.accounts.vote_record.weight;
+    let clock = Clock::get()?;
+    
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    
+    // Retracting would let the voter cast a fresh vote, which only
+    // AllowRecast permits for voided votes
+    let voided = election.votes_voided_for(candidate);
+    require!(
+        !voided || election.withdrawal_policy == WithdrawalPolicy::AllowRecast,
+        VotingError::CandidateVotesVoided
+    );
+    
+    // Voided votes were already taken off the vote tallies
+    let counted_weight = if voided { 0 } else { weight };
+    
+    candidate.vote_count = candidate.vote_count.saturating_sub(counted_weight);
+    election.total_votes = election.total_votes.saturating_sub(counted_weight);
+    election.total_ballots = election.total_ballots.saturating_sub(1);
+    
+    update_district_tally(
+        &ctx.accounts.district,
+        &election.key(),
+        candidate.district_id,
+        ctx.program_id,
+        |district| {
+            district.total_votes = district.total_votes.saturating_sub(counted_weight);
+            district.total_ballots = district.total_ballots.saturating_sub(1);
+        },
+    )?;
+    
+    msg!("Vote retracted");
+    msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("Candidate: {}", candidate.name);
+    msg!("Total election votes: {}", election.total_votes);
+    
+    Ok(())
+}
+
+// Checks that `voter` may vote in `election` and returns their voting power
+// and district. The registration and escrow accounts are only inspected when
+// the election's registration type / weighting needs them.
+pub(crate) fn voting_power(
+    election: &Account<Election>,
+    voter: &Pubkey,
+    voter_registration: &AccountInfo,
+    vote_escrow: &AccountInfo,
+    program_id: &Pubkey,
+    current_time: i64,
+) -> Result<(u64, Option<u16>)> {
     // Check if election requires voter registration
+    let mut registration_weight = 1;
+    let mut district_id = None;
     if election.voter_registration_type == VoterRegistrationType::Whitelist {
         // Manually derive and validate voter registration PDA
         let (expected_voter_reg_pda, expected_bump) = Pubkey::find_program_address(
             &[
                 VOTER_REGISTRATION_SEED,
                 election.key().as_ref(),
-                ctx.accounts.voter.key().as_ref()
+                voter.as_ref()
             ],
-            ctx.program_id
+            program_id
         );
 
         // Verify the provided account matches the expected PDA
         require!(
-            ctx.accounts.voter_registration.key() == expected_voter_reg_pda,
+            voter_registration.key() == expected_voter_reg_pda,
             VotingError::VoterNotRegistered
         );
 
         // Deserialize and validate the voter registration account
-        let voter_reg_data = ctx.accounts.voter_registration.try_borrow_data()?;
+        let voter_reg_data = voter_registration.try_borrow_data()?;
         require!(
             !voter_reg_data.is_empty(),
             VotingError::VoterNotRegistered
@@ -106,25 +442,139 @@ pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
             voter_reg.status == RegistrationStatus::Approved,
             VotingError::VoterNotRegistered
         );
+
+        registration_weight = voter_reg.weight;
+        district_id = voter_reg.district_id;
     }
     
-    // Record the vote
-    vote_record.election = election.key();
-    vote_record.voter = ctx.accounts.voter.key();
-    vote_record.candidate = candidate.key();
-    vote_record.voted_at = clock.unix_timestamp;
-    vote_record.bump = ctx.bumps.vote_record;
+    // Determine the voter's voting power
+    let weight = match election.vote_weighting {
+        VoteWeighting::Standard => registration_weight,
+        VoteWeighting::VoteEscrow { mint } => {
+            let (expected_escrow_pda, _) = Pubkey::find_program_address(
+                &[VOTE_ESCROW_SEED, voter.as_ref()],
+                program_id
+            );
 
-    // Update vote counts with overflow protection
-    candidate.vote_count = candidate.vote_count.saturating_add(1);
-    election.total_votes = election.total_votes.saturating_add(1);
-    
-    msg!("Vote cast successfully");
-    msg!("Voter: {}", ctx.accounts.voter.key());
-    msg!("Election: {}", election.title);
-    msg!("Candidate: {}", candidate.name);
-    msg!("Candidate vote count: {}", candidate.vote_count);
-    msg!("Total election votes: {}", election.total_votes);
-    
-    Ok(())
-}
\ No newline at end of file
+            require!(
+                vote_escrow.key() == expected_escrow_pda,
+                VotingError::InvalidVoteEscrow
+            );
+            require!(
+                vote_escrow.owner == program_id,
+                VotingError::InvalidVoteEscrow
+            );
+
+            let escrow_data = vote_escrow.try_borrow_data()?;
+            let vote_escrow = VoteEscrow::try_deserialize(&mut &escrow_data[..])?;
+
+            require!(vote_escrow.mint == mint, VotingError::InvalidVoteEscrow);
+
+            // The lock must outlast the election, including any extension
+            // still allowed, so the tokens cannot be withdrawn and voted
+            // again from another wallet
+            require!(
+                vote_escrow.lock_end >= election.latest_end_time(),
+                VotingError::EscrowUnlocksBeforeElectionEnds
+            );
+
+            vote_escrow.voting_power(current_time)
+        }
+    };
+    require!(weight > 0, VotingError::NoVotingPower);
+
+    Ok((weight, district_id))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::*;
+    use crate::{accounts, instruction};
+
+    // Active election 0 where the voter's vote for a withdrawn candidate was
+    // voided under `policy`; returns the vote record and the voter
+    fn voided_vote(ledger: &mut TestLedger, policy: WithdrawalPolicy) -> (Pubkey, Pubkey) {
+        setup_admin(ledger);
+
+        let (election_key, mut election) = draft_election(0);
+        election.status = ElectionStatus::Active;
+        election.start_time = 0;
+        election.end_time = 10_000;
+        election.withdrawal_policy = policy;
+        election.total_ballots = 1;
+
+        let (candidate_key, mut candidate) = active_candidate(election_key, 0);
+        candidate.status = CandidateStatus::Withdrawn;
+        candidate.voided_votes = 2;
+
+        let voter = Pubkey::new_unique();
+        let (record_key, bump) =
+            pda(&[VOTE_RECORD_SEED, election_key.as_ref(), voter.as_ref()]);
+        let record = VoteRecord {
+            election: election_key,
+            voter,
+            candidate: candidate_key,
+            weight: 2,
+            cast_by: voter,
+            delegated_weight: 0,
+            payer: voter,
+            voted_at: 500,
+            revision: 0,
+            bump,
+        };
+
+        ledger.set_account(election_key, &election, Election::SIZE);
+        ledger.set_account(candidate_key, &candidate, Candidate::SIZE);
+        ledger.set_account(record_key, &record, VoteRecord::SIZE);
+        (record_key, voter)
+    }
+
+    fn retract(
+        ledger: &mut TestLedger,
+        vote_record: Pubkey,
+        voter: Pubkey,
+    ) -> std::result::Result<(), ProgramError> {
+        let (election, _) = draft_election(0);
+        ledger.process(
+            accounts::RetractVote {
+                admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                election,
+                vote_record,
+                candidate: active_candidate(election, 0).0,
+                district: Pubkey::new_unique(),
+                rent_recipient: voter,
+                voter,
+            },
+            instruction::RetractVote {},
+            &[],
+        )
+    }
+
+    #[test]
+    fn voided_votes_cannot_be_retracted_to_vote_again() {
+        let mut ledger = TestLedger::new(1_000);
+        let (record, voter) = voided_vote(&mut ledger, WithdrawalPolicy::VoidVotes);
+
+        assert_eq!(
+            retract(&mut ledger, record, voter),
+            Err(error(VotingError::CandidateVotesVoided))
+        );
+        // The record stays, so cast_vote cannot create a new one
+        assert!(ledger.exists(&record));
+    }
+
+    #[test]
+    fn voided_votes_can_be_retracted_when_recasting_is_allowed() {
+        let mut ledger = TestLedger::new(1_000);
+        let (record, voter) = voided_vote(&mut ledger, WithdrawalPolicy::AllowRecast);
+
+        retract(&mut ledger, record, voter).unwrap();
+
+        assert!(!ledger.exists(&record));
+        let (election_key, _) = draft_election(0);
+        let election = ledger.get::<Election>(&election_key);
+        // The voided weight had already left the tallies
+        assert_eq!(election.total_votes, 0);
+        assert_eq!(election.total_ballots, 0);
+    }
+}
@@ -0,0 +1,336 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::instructions::cast_vote::voting_power;
+use crate::instructions::vault::{is_election_vault, reimburse_rent};
+use crate::state::*;
+use crate::utils::{create_pda_account, write_account};
+
+// CREATE CONTEST
+#[derive(Accounts)]
+#[instruction(title: String)]
+pub struct CreateContest<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Contest::SIZE,
+        seeds = [
+            CONTEST_SEED,
+            election.key().as_ref(),
+            election.contest_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_contest(
+    ctx: Context<CreateContest>,
+    title: String,
+    seats: u8,
+    ballot_type: BallotType,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // Validate title (required, non-empty)
+    require!(
+        !title.trim().is_empty(),
+        VotingError::InvalidInput
+    );
+    require!(
+        title.len() <= MAX_TITLE_LENGTH,
+        VotingError::TitleTooLong
+    );
+
+    require!(
+        seats > 0 && seats <= MAX_CONTEST_CHOICES,
+        VotingError::InvalidInput
+    );
+
+    let contest = &mut ctx.accounts.contest;
+    let election = &mut ctx.accounts.election;
+
+    contest.election = election.key();
+    contest.contest_id = election.contest_count;
+    contest.title = title;
+    contest.seats = seats;
+    contest.ballot_type = ballot_type;
+    contest.total_votes = 0;
+    contest.total_ballots = 0;
+    contest.payer = ctx.accounts.authority.key();
+    contest.bump = ctx.bumps.contest;
+
+    election.contest_count = election
+        .contest_count
+        .checked_add(1)
+        .ok_or(VotingError::InvalidContest)?;
+
+    msg!("Contest created");
+    msg!("Election: {}", election.title);
+    msg!("Contest: {}", contest.title);
+    msg!("Contest ID: {}", contest.contest_id);
+
+    Ok(())
+}
+
+// CAST BALLOT (choices for several contests in one transaction)
+//
+// Remaining accounts hold one group per contest:
+// [contest, contest vote record, chosen candidates...], where the number of
+// chosen candidates in each group is given by `choice_counts`.
+// Delegated power is not applied to contest votes.
+#[derive(Accounts)]
+pub struct CastBallot<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+
+    /// CHECK: Optional voter registration. For Whitelist elections, seeds/bump/status validated in cast_ballot function.
+    pub voter_registration: UncheckedAccount<'info>,
+
+    /// CHECK: Optional vote escrow. For VoteEscrow elections, seeds/owner/mint validated in cast_ballot function.
+    pub vote_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Optional election vault. Seeds/owner validated in cast_ballot function.
+    #[account(mut)]
+    pub election_vault: UncheckedAccount<'info>,
+
+    pub voter: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cast_ballot<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastBallot<'info>>,
+    choice_counts: Vec<u8>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(!choice_counts.is_empty(), VotingError::InvalidBallotSelection);
+
+    let election = &ctx.accounts.election;
+    let election_key = election.key();
+    let voter = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+
+    // Enforce the voting window
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    require!(
+        election.can_vote(clock.unix_timestamp),
+        VotingError::ElectionNotActive
+    );
+
+    let (weight, district_id) = voting_power(
+        election,
+        &voter,
+        &ctx.accounts.voter_registration,
+        &ctx.accounts.vote_escrow,
+        ctx.program_id,
+        clock.unix_timestamp,
+    )?;
+
+    let sponsored = is_election_vault(&ctx.accounts.election_vault, &election_key, ctx.program_id);
+    let rent_payer = if sponsored {
+        ctx.accounts.election_vault.key()
+    } else {
+        ctx.accounts.payer.key()
+    };
+
+    let expected_accounts: usize = choice_counts.iter().map(|count| 2 + *count as usize).sum();
+    require!(
+        ctx.remaining_accounts.len() == expected_accounts,
+        VotingError::InvalidBallotSelection
+    );
+
+    let mut remaining = ctx.remaining_accounts;
+    for choice_count in choice_counts.iter() {
+        let (group, rest) = remaining.split_at(2 + *choice_count as usize);
+        remaining = rest;
+
+        let contest_info = &group[0];
+        let contest_record_info = &group[1];
+        let candidate_infos = &group[2..];
+
+        // Load and validate the contest
+        require!(
+            contest_info.owner == ctx.program_id,
+            VotingError::InvalidContest
+        );
+        let mut contest = {
+            let data = contest_info.try_borrow_data()?;
+            Contest::try_deserialize(&mut &data[..])?
+        };
+        let (expected_contest_pda, _) = Pubkey::find_program_address(
+            &[
+                CONTEST_SEED,
+                election_key.as_ref(),
+                contest.contest_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            contest_info.key() == expected_contest_pda,
+            VotingError::InvalidContest
+        );
+        require!(
+            contest.is_valid_choice_count(candidate_infos.len()),
+            VotingError::InvalidBallotSelection
+        );
+
+        // One vote record per contest and voter
+        let (contest_record_pda, contest_record_bump) = Pubkey::find_program_address(
+            &[
+                CONTEST_VOTE_RECORD_SEED,
+                contest_info.key().as_ref(),
+                voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            contest_record_info.key() == contest_record_pda,
+            VotingError::InvalidContest
+        );
+        require!(
+            contest_record_info.data_is_empty(),
+            VotingError::AlreadyVoted
+        );
+
+        let mut chosen: Vec<Pubkey> = Vec::with_capacity(candidate_infos.len());
+        for candidate_info in candidate_infos.iter() {
+            require!(
+                candidate_info.owner == ctx.program_id,
+                VotingError::InvalidCandidate
+            );
+            require!(
+                !chosen.contains(&candidate_info.key()),
+                VotingError::InvalidBallotSelection
+            );
+
+            let mut candidate = {
+                let data = candidate_info.try_borrow_data()?;
+                Candidate::try_deserialize(&mut &data[..])?
+            };
+            let (expected_candidate_pda, _) = Pubkey::find_program_address(
+                &[
+                    CANDIDATE_SEED,
+                    election_key.as_ref(),
+                    candidate.candidate_id.to_le_bytes().as_ref()
+                ],
+                ctx.program_id
+            );
+            require!(
+                candidate_info.key() == expected_candidate_pda,
+                VotingError::InvalidCandidate
+            );
+            require!(
+                candidate.contest_id == Some(contest.contest_id),
+                VotingError::InvalidCandidate
+            );
+            require!(
+                candidate.is_active(),
+                VotingError::CandidateInactive
+            );
+            require!(
+                candidate.district_id.is_none() || candidate.district_id == district_id,
+                VotingError::CandidateOutsideDistrict
+            );
+
+            candidate.vote_count = candidate.vote_count.saturating_add(weight);
+            write_account(&candidate, candidate_info)?;
+
+            chosen.push(candidate_info.key());
+        }
+
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            contest_record_info,
+            &ctx.accounts.system_program.to_account_info(),
+            ContestVoteRecord::SIZE,
+            ctx.program_id,
+            &[
+                CONTEST_VOTE_RECORD_SEED,
+                contest_info.key().as_ref(),
+                voter.as_ref(),
+                &[contest_record_bump],
+            ],
+        )?;
+
+        let contest_record = ContestVoteRecord {
+            contest: contest_info.key(),
+            voter,
+            candidates: chosen,
+            weight,
+            payer: rent_payer,
+            voted_at: clock.unix_timestamp,
+            bump: contest_record_bump,
+        };
+        write_account(&contest_record, contest_record_info)?;
+
+        contest.total_votes = contest
+            .total_votes
+            .saturating_add(weight.saturating_mul(candidate_infos.len() as u64));
+        contest.total_ballots = contest.total_ballots.saturating_add(1);
+        write_account(&contest, contest_info)?;
+
+        msg!("Contest: {}", contest.title);
+        msg!("Choices: {}", candidate_infos.len());
+    }
+
+    if sponsored {
+        reimburse_rent(
+            &ctx.accounts.election_vault,
+            &ctx.accounts.payer.to_account_info(),
+            ContestVoteRecord::SIZE,
+            choice_counts.len() as u64,
+        )?;
+    }
+
+    msg!("Ballot cast successfully");
+    msg!("Voter: {}", voter);
+    msg!("Election: {}", election.title);
+    msg!("Vote weight: {}", weight);
+
+    Ok(())
+}
@@ -0,0 +1,586 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::instructions::cast_vote::voting_power;
+use crate::instructions::district::update_district_tally;
+use crate::instructions::vault::{is_election_vault, reimburse_rent};
+use crate::state::*;
+use crate::utils::{create_pda_account, write_account};
+
+// Remaining accounts passed per delegator when votes are applied:
+// [delegation, election-scoped delegation PDA, vote record, voter registration, vote escrow]
+const DELEGATOR_ACCOUNTS: usize = 5;
+
+// CREATE DELEGATION
+#[derive(Accounts)]
+pub struct CreateDelegation<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        init,
+        payer = delegator,
+        space = Delegation::SIZE,
+        seeds = [
+            DELEGATION_SEED,
+            delegator.key().as_ref(),
+            election.key().as_ref()
+        ],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// CHECK: Election the delegation is scoped to, or the System Program (the default pubkey) for a global delegation. Validated in create_delegation function.
+    pub election: UncheckedAccount<'info>,
+
+    /// CHECK: The wallet receiving the voting power
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_delegation(ctx: Context<CreateDelegation>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // A scoped delegation must name an existing election
+    let scope = ctx.accounts.election.key();
+    let election = if scope == Delegation::scope(None) {
+        None
+    } else {
+        let election_info = ctx.accounts.election.to_account_info();
+        require!(
+            election_info.owner == ctx.program_id,
+            VotingError::ElectionNotFound
+        );
+        let data = election_info.try_borrow_data()?;
+        Election::try_deserialize(&mut &data[..])?;
+        Some(scope)
+    };
+
+    let delegator = ctx.accounts.delegator.key();
+    let delegate = ctx.accounts.delegate.key();
+    require!(delegate != delegator, VotingError::SelfDelegation);
+
+    // Walk the delegate's existing chain in the same scope. Remaining accounts
+    // hold one delegation PDA per hop and must end with an empty PDA, which
+    // proves the chain terminates there.
+    let mut current = delegate;
+    let mut depth: u8 = 1;
+    let mut terminated = false;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[DELEGATION_SEED, current.as_ref(), scope.as_ref()],
+            ctx.program_id
+        );
+        require!(
+            account_info.key() == expected_pda,
+            VotingError::InvalidDelegation
+        );
+
+        if account_info.data_is_empty() {
+            terminated = true;
+            break;
+        }
+
+        let data = account_info.try_borrow_data()?;
+        let next = Delegation::try_deserialize(&mut &data[..])?.delegate;
+
+        require!(next != delegator, VotingError::DelegationCycle);
+        depth = depth.saturating_add(1);
+        require!(
+            depth <= MAX_DELEGATION_DEPTH,
+            VotingError::DelegationDepthExceeded
+        );
+
+        current = next;
+    }
+    require!(terminated, VotingError::InvalidDelegation);
+
+    let delegation = &mut ctx.accounts.delegation;
+    let clock = Clock::get()?;
+
+    delegation.delegator = delegator;
+    delegation.delegate = delegate;
+    delegation.election = election;
+    delegation.created_at = clock.unix_timestamp;
+    delegation.bump = ctx.bumps.delegation;
+
+    msg!("Delegation created");
+    msg!("Delegator: {}", delegator);
+    msg!("Delegate: {}", delegate);
+
+    Ok(())
+}
+
+// REVOKE DELEGATION
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [
+            DELEGATION_SEED,
+            delegator.key().as_ref(),
+            election.key().as_ref()
+        ],
+        bump = delegation.bump,
+        has_one = delegator @ VotingError::Unauthorized
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// CHECK: The delegation's scope: its election, or the System Program for a global delegation
+    #[account(
+        constraint = election.key() == Delegation::scope(delegation.election) @ VotingError::InvalidDelegation
+    )]
+    pub election: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+
+    msg!("Delegation revoked");
+    msg!("Delegator: {}", delegation.delegator);
+    msg!("Delegate: {}", delegation.delegate);
+
+    Ok(())
+}
+
+// APPLY DELEGATED VOTES (delegate adds more delegators after voting)
+#[derive(Accounts)]
+pub struct ApplyDelegatedVotes<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        constraint = candidate.key() == vote_record.candidate @ VotingError::InvalidCandidate,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    #[account(
+        mut,
+        seeds = [
+            VOTE_RECORD_SEED,
+            election.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = !vote_record.is_delegated() @ VotingError::InvalidDelegation
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// CHECK: Optional district. For districted elections, seeds validated in apply_delegated_votes function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+
+    /// CHECK: Optional election vault. Seeds/owner validated in apply_delegated_votes function.
+    #[account(mut)]
+    pub election_vault: UncheckedAccount<'info>,
+
+    pub voter: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn apply_delegated_votes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApplyDelegatedVotes<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let election = &mut ctx.accounts.election;
+    let candidate = &mut ctx.accounts.candidate;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+
+    // Enforce the voting window
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    require!(
+        election.can_vote(clock.unix_timestamp),
+        VotingError::ElectionNotActive
+    );
+
+    let sponsored = is_election_vault(&ctx.accounts.election_vault, &election.key(), ctx.program_id);
+    let rent_payer = if sponsored {
+        ctx.accounts.election_vault.key()
+    } else {
+        ctx.accounts.payer.key()
+    };
+
+    let (delegated_weight, delegator_count) = apply_delegations(
+        election,
+        vote_record,
+        ctx.remaining_accounts,
+        &ctx.accounts.payer.to_account_info(),
+        rent_payer,
+        &ctx.accounts.system_program.to_account_info(),
+        candidate.district_id,
+    )?;
+
+    if sponsored {
+        reimburse_rent(
+            &ctx.accounts.election_vault,
+            &ctx.accounts.payer.to_account_info(),
+            VoteRecord::SIZE,
+            delegator_count as u64,
+        )?;
+    }
+
+    vote_record.delegated_weight = vote_record.delegated_weight.saturating_add(delegated_weight);
+    candidate.vote_count = candidate.vote_count.saturating_add(delegated_weight);
+    election.total_votes = election.total_votes.saturating_add(delegated_weight);
+    election.total_ballots = election.total_ballots.saturating_add(delegator_count as u64);
+
+    update_district_tally(
+        &ctx.accounts.district,
+        &election.key(),
+        candidate.district_id,
+        ctx.program_id,
+        |district| {
+            district.total_votes = district.total_votes.saturating_add(delegated_weight);
+            district.total_ballots = district.total_ballots.saturating_add(delegator_count as u64);
+        },
+    )?;
+
+    msg!("Delegated votes applied");
+    msg!("Delegate: {}", ctx.accounts.voter.key());
+    msg!("Delegators counted: {}", delegator_count);
+    msg!("Candidate vote count: {}", candidate.vote_count);
+
+    Ok(())
+}
+
+// OVERRIDE DELEGATED VOTE (delegator votes directly after their delegate)
+#[derive(Accounts)]
+pub struct OverrideDelegatedVote<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            VOTE_RECORD_SEED,
+            election.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = vote_record.is_delegated() @ VotingError::VoteNotDelegated
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    // Choice of the vote the delegator's power currently follows; validated
+    // against the delegate chain in override_delegated_vote function
+    #[account(mut)]
+    pub previous_candidate: Account<'info, Candidate>,
+
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = candidate.election == election.key() @ VotingError::InvalidCandidate,
+        constraint = candidate.contest_id.is_none() @ VotingError::InvalidCandidate,
+        constraint = candidate.district_id == previous_candidate.district_id @ VotingError::CandidateOutsideDistrict,
+        constraint = candidate.is_active() @ VotingError::CandidateInactive
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    /// CHECK: Optional district. For districted elections, seeds validated in override_delegated_vote function.
+    #[account(mut)]
+    pub district: UncheckedAccount<'info>,
+
+    pub voter: Signer<'info>,
+}
+
+pub fn override_delegated_vote(ctx: Context<OverrideDelegatedVote>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= ctx.accounts.election.end_time,
+        VotingError::ElectionEnded
+    );
+
+    // The delegator's own power plus everyone still following them
+    let vote_record = &mut ctx.accounts.vote_record;
+    let weight = vote_record.weight.saturating_add(vote_record.delegated_weight);
+
+    // Remaining accounts hold the delegate chain's vote records, from the
+    // delegator's delegate up to the first vote cast directly. Each carried
+    // this weight, and the direct vote's candidate received it.
+    let election_key = ctx.accounts.election.key();
+    let mut delegate = vote_record.cast_by;
+    let mut chain_candidate = None;
+    for record_info in ctx.remaining_accounts.iter() {
+        require!(chain_candidate.is_none(), VotingError::InvalidDelegation);
+
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[VOTE_RECORD_SEED, election_key.as_ref(), delegate.as_ref()],
+            ctx.program_id
+        );
+        require!(
+            record_info.key() == expected_pda && record_info.owner == ctx.program_id,
+            VotingError::InvalidDelegation
+        );
+
+        let mut record = {
+            let data = record_info.try_borrow_data()?;
+            VoteRecord::try_deserialize(&mut &data[..])?
+        };
+        record.delegated_weight = record.delegated_weight.saturating_sub(weight);
+        write_account(&record, record_info)?;
+
+        if record.is_delegated() {
+            delegate = record.cast_by;
+        } else {
+            chain_candidate = Some(record.candidate);
+        }
+    }
+    require!(
+        chain_candidate == Some(ctx.accounts.previous_candidate.key()),
+        VotingError::InvalidCandidate
+    );
+
+    if ctx.accounts.previous_candidate.key() != ctx.accounts.candidate.key() {
+        let election = &mut ctx.accounts.election;
+        let previous_candidate = &mut ctx.accounts.previous_candidate;
+        if election.votes_voided_for(previous_candidate) {
+            // The delegate's choice was voided; recasting counts it again
+            require!(
+                election.withdrawal_policy == WithdrawalPolicy::AllowRecast,
+                VotingError::CandidateVotesVoided
+            );
+            election.total_votes = election.total_votes.saturating_add(weight);
+            update_district_tally(
+                &ctx.accounts.district,
+                &election.key(),
+                previous_candidate.district_id,
+                ctx.program_id,
+                |district| district.total_votes = district.total_votes.saturating_add(weight),
+            )?;
+        } else {
+            previous_candidate.vote_count = previous_candidate.vote_count.saturating_sub(weight);
+        }
+
+        let candidate = &mut ctx.accounts.candidate;
+        candidate.vote_count = candidate.vote_count.saturating_add(weight);
+    }
+
+    // The delegator now votes directly; their own delegators follow them
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.candidate = ctx.accounts.candidate.key();
+    vote_record.cast_by = ctx.accounts.voter.key();
+    vote_record.voted_at = clock.unix_timestamp;
+    vote_record.revision = vote_record.revision.saturating_add(1);
+
+    msg!("Delegated vote overridden");
+    msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("Candidate: {}", ctx.accounts.candidate.name);
+    msg!("Vote weight: {}", weight);
+
+    Ok(())
+}
+
+// Counts the voting power of delegators who have not voted themselves towards
+// `vote_record`'s choice, creating a vote record for each so they cannot be
+// counted twice. Delegators are passed as remaining accounts in groups of
+// DELEGATOR_ACCOUNTS; each must delegate to the voter or to a delegator listed
+// before it, and belong to the voter's district. A delegated record names its
+// delegate in `cast_by` rather than a candidate, so it follows that vote if it
+// changes, and adds its weight to the `delegated_weight` of every record up
+// the chain. Returns the weight added and the number of delegators counted.
+pub(crate) fn apply_delegations<'info>(
+    election: &Account<'info, Election>,
+    vote_record: &VoteRecord,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    rent_payer: Pubkey,
+    system_program: &AccountInfo<'info>,
+    district_id: Option<u16>,
+) -> Result<(u64, u32)> {
+    let program_id = &crate::ID;
+    require!(
+        remaining_accounts.len() % DELEGATOR_ACCOUNTS == 0,
+        VotingError::InvalidDelegation
+    );
+
+    let election_key = election.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    // (wallet, chain depth, index in `records`) of everyone whose vote now
+    // carries delegated power; the voter's own record is updated by the caller
+    let mut counted: Vec<(Pubkey, u8, Option<usize>)> = vec![(vote_record.voter, 0, None)];
+    // New delegator records and the index of their delegate's new record
+    let mut records: Vec<(&AccountInfo<'info>, VoteRecord, Option<usize>)> = vec![];
+    let mut delegated_weight: u64 = 0;
+
+    for accounts in remaining_accounts.chunks_exact(DELEGATOR_ACCOUNTS) {
+        let [delegation_info, scoped_delegation_info, delegator_record_info, voter_registration, vote_escrow] = accounts else {
+            unreachable!()
+        };
+
+        require!(
+            delegation_info.owner == program_id,
+            VotingError::InvalidDelegation
+        );
+        let delegation = {
+            let data = delegation_info.try_borrow_data()?;
+            Delegation::try_deserialize(&mut &data[..])?
+        };
+        let delegator = delegation.delegator;
+
+        let (expected_delegation_pda, _) = Pubkey::find_program_address(
+            &[
+                DELEGATION_SEED,
+                delegator.as_ref(),
+                Delegation::scope(delegation.election).as_ref()
+            ],
+            program_id
+        );
+        require!(
+            delegation_info.key() == expected_delegation_pda,
+            VotingError::InvalidDelegation
+        );
+
+        // A delegation for this election takes precedence over a global one
+        let (scoped_delegation_pda, _) = Pubkey::find_program_address(
+            &[DELEGATION_SEED, delegator.as_ref(), election_key.as_ref()],
+            program_id
+        );
+        require!(
+            scoped_delegation_info.key() == scoped_delegation_pda,
+            VotingError::InvalidDelegation
+        );
+        match delegation.election {
+            Some(scope) => require!(scope == election_key, VotingError::InvalidDelegation),
+            None => require!(
+                scoped_delegation_info.data_is_empty(),
+                VotingError::InvalidDelegation
+            ),
+        }
+
+        let (parent_depth, parent_index) = counted
+            .iter()
+            .find(|(wallet, _, _)| *wallet == delegation.delegate)
+            .map(|(_, depth, index)| (*depth, *index))
+            .ok_or(VotingError::InvalidDelegation)?;
+        require!(
+            parent_depth < MAX_DELEGATION_DEPTH,
+            VotingError::DelegationDepthExceeded
+        );
+
+        let (delegator_record_pda, delegator_record_bump) = Pubkey::find_program_address(
+            &[VOTE_RECORD_SEED, election_key.as_ref(), delegator.as_ref()],
+            program_id
+        );
+        require!(
+            delegator_record_info.key() == delegator_record_pda,
+            VotingError::InvalidDelegation
+        );
+
+        // Delegators who already voted (directly or through another
+        // delegate) keep that vote
+        if !delegator_record_info.data_is_empty() {
+            continue;
+        }
+
+        let (weight, delegator_district_id) = voting_power(
+            election,
+            &delegator,
+            voter_registration,
+            vote_escrow,
+            program_id,
+            current_time,
+        )?;
+        require!(
+            delegator_district_id == district_id,
+            VotingError::CandidateOutsideDistrict
+        );
+
+        create_pda_account(
+            payer,
+            delegator_record_info,
+            system_program,
+            VoteRecord::SIZE,
+            program_id,
+            &[
+                VOTE_RECORD_SEED,
+                election_key.as_ref(),
+                delegator.as_ref(),
+                &[delegator_record_bump],
+            ],
+        )?;
+
+        let delegator_record = VoteRecord {
+            election: election_key,
+            voter: delegator,
+            candidate: Pubkey::default(),
+            weight,
+            cast_by: delegation.delegate,
+            delegated_weight: 0,
+            payer: rent_payer,
+            voted_at: current_time,
+            revision: 0,
+            bump: delegator_record_bump,
+        };
+
+        let mut ancestor = parent_index;
+        while let Some(index) = ancestor {
+            let (_, record, parent) = &mut records[index];
+            record.delegated_weight = record.delegated_weight.saturating_add(weight);
+            ancestor = *parent;
+        }
+        delegated_weight = delegated_weight.saturating_add(weight);
+
+        counted.push((delegator, parent_depth + 1, Some(records.len())));
+        records.push((delegator_record_info, delegator_record, parent_index));
+    }
+
+    for (info, record, _) in records.iter() {
+        write_account(record, info)?;
+    }
+
+    Ok((delegated_weight, (counted.len() - 1) as u32))
+}
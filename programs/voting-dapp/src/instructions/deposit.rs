@@ -0,0 +1,553 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+use crate::utils::write_account;
+
+// CONFIGURE CANDIDATE DEPOSIT (election creator, Draft only)
+#[derive(Accounts)]
+pub struct ConfigureCandidateDeposit<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        has_one = authority @ VotingError::Unauthorized,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.deposits_held == 0 @ VotingError::DepositsAlreadyPaid
+    )]
+    pub election: Account<'info, Election>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_candidate_deposit(
+    ctx: Context<ConfigureCandidateDeposit>,
+    amount: u64,
+    mint: Option<Pubkey>,
+    refund_bps: u16,
+    treasury: Pubkey,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // A zero amount turns deposits off
+    require!(
+        refund_bps <= 10_000,
+        VotingError::InvalidInput
+    );
+    // A forfeited SOL deposit must be able to open an empty treasury account
+    require!(
+        amount == 0 || mint.is_some() || amount >= Rent::get()?.minimum_balance(0),
+        VotingError::DepositBelowRentExempt
+    );
+    require!(
+        treasury != Pubkey::default(),
+        VotingError::InvalidTreasury
+    );
+
+    let election = &mut ctx.accounts.election;
+    election.deposit_amount = amount;
+    election.deposit_mint = mint;
+    election.deposit_refund_bps = refund_bps;
+    election.deposit_treasury = treasury;
+
+    msg!("Candidate deposit configured");
+    msg!("Election: {}", election.title);
+    msg!("Amount: {}", amount);
+    match mint {
+        Some(mint) => msg!("Mint: {}", mint),
+        None => msg!("Currency: SOL"),
+    }
+    msg!("Refund threshold (bps): {}", refund_bps);
+    msg!("Treasury: {}", treasury);
+
+    Ok(())
+}
+
+// PAY CANDIDATE DEPOSIT (SOL, held on the deposit account)
+#[derive(Accounts)]
+pub struct PayCandidateDeposit<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.requires_deposit() && election.deposit_mint.is_none() @ VotingError::DepositNotRequired
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = !candidate.deposit_paid @ VotingError::DepositsAlreadyPaid
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = CandidateDeposit::SIZE,
+        seeds = [CANDIDATE_DEPOSIT_SEED, candidate.key().as_ref()],
+        bump
+    )]
+    pub deposit: Account<'info, CandidateDeposit>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pay_candidate_deposit(ctx: Context<PayCandidateDeposit>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let amount = ctx.accounts.election.deposit_amount;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.deposit.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    record_deposit(
+        &mut ctx.accounts.election,
+        &mut ctx.accounts.candidate,
+        &mut ctx.accounts.deposit,
+        ctx.accounts.depositor.key(),
+        ctx.bumps.deposit,
+        0,
+    )
+}
+
+// PAY CANDIDATE DEPOSIT TOKEN (SPL token, held in a deposit vault)
+#[derive(Accounts)]
+pub struct PayCandidateDepositToken<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.requires_deposit() && election.deposit_mint == Some(mint.key()) @ VotingError::DepositNotRequired
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = !candidate.deposit_paid @ VotingError::DepositsAlreadyPaid
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = CandidateDeposit::SIZE,
+        seeds = [CANDIDATE_DEPOSIT_SEED, candidate.key().as_ref()],
+        bump
+    )]
+    pub deposit: Account<'info, CandidateDeposit>,
+
+    #[account(
+        init,
+        payer = depositor,
+        token::mint = mint,
+        token::authority = deposit,
+        seeds = [DEPOSIT_VAULT_SEED, deposit.key().as_ref()],
+        bump
+    )]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pay_candidate_deposit_token(ctx: Context<PayCandidateDepositToken>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let amount = ctx.accounts.election.deposit_amount;
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.deposit_vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    record_deposit(
+        &mut ctx.accounts.election,
+        &mut ctx.accounts.candidate,
+        &mut ctx.accounts.deposit,
+        ctx.accounts.depositor.key(),
+        ctx.bumps.deposit,
+        ctx.bumps.deposit_vault,
+    )
+}
+
+fn record_deposit(
+    election: &mut Account<Election>,
+    candidate: &mut Account<Candidate>,
+    deposit: &mut Account<CandidateDeposit>,
+    depositor: Pubkey,
+    bump: u8,
+    vault_bump: u8,
+) -> Result<()> {
+    deposit.election = election.key();
+    deposit.candidate = candidate.key();
+    deposit.depositor = depositor;
+    deposit.mint = election.deposit_mint;
+    deposit.amount = election.deposit_amount;
+    deposit.paid_at = Clock::get()?.unix_timestamp;
+    deposit.bump = bump;
+    deposit.vault_bump = vault_bump;
+
+    candidate.deposit_paid = true;
+    election.deposited_candidates = election.deposited_candidates.saturating_add(1);
+    election.deposits_held = election.deposits_held.saturating_add(1);
+
+    msg!("Candidate deposit paid");
+    msg!("Candidate: {}", candidate.name);
+    msg!("Depositor: {}", depositor);
+    msg!("Amount: {}", deposit.amount);
+
+    Ok(())
+}
+
+// SETTLE CANDIDATE DEPOSIT (permissionless, SOL)
+//
+// The deposit is refunded to the depositor when the candidate reached the
+// refund threshold, and forfeited to the treasury otherwise.
+#[derive(Accounts)]
+pub struct SettleCandidateDeposit<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [CANDIDATE_DEPOSIT_SEED, deposit.candidate.as_ref()],
+        bump = deposit.bump,
+        has_one = election @ VotingError::InvalidInput,
+        has_one = candidate @ VotingError::InvalidCandidate,
+        has_one = depositor @ VotingError::InvalidRentRecipient,
+        constraint = deposit.mint.is_none() @ VotingError::DepositNotRequired
+    )]
+    pub deposit: Account<'info, CandidateDeposit>,
+
+    /// CHECK: Candidate the deposit is for; may already be removed. Checked against the deposit.
+    #[account(mut)]
+    pub candidate: UncheckedAccount<'info>,
+
+    /// CHECK: Optional district. For districted candidates, seeds validated in settle_candidate_deposit function.
+    pub district: UncheckedAccount<'info>,
+
+    /// CHECK: Optional contest. For contest candidates, seeds validated in settle_candidate_deposit function.
+    pub contest: UncheckedAccount<'info>,
+
+    /// CHECK: Depositor receiving refunds and the deposit account's rent. Checked against the deposit.
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    /// CHECK: Receives forfeited deposits; must be the election's treasury.
+    #[account(
+        mut,
+        constraint = treasury.key() == election.deposit_treasury @ VotingError::InvalidTreasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn settle_candidate_deposit(ctx: Context<SettleCandidateDeposit>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let refunded = settle_deposit(
+        &mut ctx.accounts.election,
+        &ctx.accounts.candidate,
+        &ctx.accounts.district,
+        &ctx.accounts.contest,
+        ctx.program_id,
+    )?;
+
+    // On refund, closing the deposit returns everything to the depositor
+    let amount = ctx.accounts.deposit.amount;
+    if !refunded {
+        let deposit_info = ctx.accounts.deposit.to_account_info();
+        **deposit_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += amount;
+    }
+
+    log_settlement(refunded, amount, &ctx.accounts.depositor.key(), &ctx.accounts.treasury.key());
+
+    Ok(())
+}
+
+// SETTLE CANDIDATE DEPOSIT TOKEN (permissionless, SPL token)
+#[derive(Accounts)]
+pub struct SettleCandidateDepositToken<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [CANDIDATE_DEPOSIT_SEED, deposit.candidate.as_ref()],
+        bump = deposit.bump,
+        has_one = election @ VotingError::InvalidInput,
+        has_one = candidate @ VotingError::InvalidCandidate,
+        has_one = depositor @ VotingError::InvalidRentRecipient,
+        constraint = deposit.mint.is_some() @ VotingError::DepositNotRequired
+    )]
+    pub deposit: Account<'info, CandidateDeposit>,
+
+    #[account(
+        mut,
+        seeds = [DEPOSIT_VAULT_SEED, deposit.key().as_ref()],
+        bump = deposit.vault_bump
+    )]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Candidate the deposit is for; may already be removed. Checked against the deposit.
+    #[account(mut)]
+    pub candidate: UncheckedAccount<'info>,
+
+    /// CHECK: Optional district. For districted candidates, seeds validated in settle_candidate_deposit_token function.
+    pub district: UncheckedAccount<'info>,
+
+    /// CHECK: Optional contest. For contest candidates, seeds validated in settle_candidate_deposit_token function.
+    pub contest: UncheckedAccount<'info>,
+
+    /// CHECK: Depositor receiving the rent of the closed accounts. Checked against the deposit.
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = deposit_vault.mint,
+        token::authority = deposit.depositor
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = deposit_vault.mint,
+        token::authority = election.deposit_treasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn settle_candidate_deposit_token(ctx: Context<SettleCandidateDepositToken>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let refunded = settle_deposit(
+        &mut ctx.accounts.election,
+        &ctx.accounts.candidate,
+        &ctx.accounts.district,
+        &ctx.accounts.contest,
+        ctx.program_id,
+    )?;
+
+    let recipient = if refunded {
+        ctx.accounts.depositor_token_account.to_account_info()
+    } else {
+        ctx.accounts.treasury_token_account.to_account_info()
+    };
+
+    let candidate_key = ctx.accounts.deposit.candidate;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CANDIDATE_DEPOSIT_SEED,
+        candidate_key.as_ref(),
+        &[ctx.accounts.deposit.bump],
+    ]];
+
+    // Pay out the tokens and close the vault
+    let amount = ctx.accounts.deposit_vault.amount;
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.deposit_vault.to_account_info(),
+                to: recipient,
+                authority: ctx.accounts.deposit.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.deposit_vault.to_account_info(),
+            destination: ctx.accounts.depositor.to_account_info(),
+            authority: ctx.accounts.deposit.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    log_settlement(refunded, amount, &ctx.accounts.depositor.key(), &ctx.accounts.treasury_token_account.key());
+
+    Ok(())
+}
+
+// Decides whether a deposit is refunded. Candidates removed from a draft and
+// candidates of a cancelled election are refunded. After finalization the
+// candidate needs `deposit_refund_bps` of the votes in its race (its contest,
+// its district, or the whole election); disqualified candidates forfeit.
+fn settle_deposit(
+    election: &mut Account<Election>,
+    candidate_info: &AccountInfo,
+    district_info: &AccountInfo,
+    contest_info: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<bool> {
+    let refunded = if candidate_info.data_is_empty() {
+        require!(
+            matches!(election.status, ElectionStatus::Draft | ElectionStatus::Cancelled),
+            VotingError::DepositNotSettleable
+        );
+        true
+    } else {
+        require!(
+            candidate_info.owner == program_id,
+            VotingError::InvalidCandidate
+        );
+        let mut candidate = {
+            let data = candidate_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+
+        let refunded = match election.status {
+            ElectionStatus::Cancelled => true,
+            ElectionStatus::Finalized => {
+                let race_votes = match (candidate.contest_id, candidate.district_id) {
+                    (None, None) => election.total_votes,
+                    (None, Some(district_id)) => {
+                        let (expected_district_pda, _) = Pubkey::find_program_address(
+                            &[
+                                DISTRICT_SEED,
+                                election.key().as_ref(),
+                                district_id.to_le_bytes().as_ref()
+                            ],
+                            program_id
+                        );
+                        require!(
+                            district_info.key() == expected_district_pda && district_info.owner == program_id,
+                            VotingError::InvalidDistrict
+                        );
+                        let data = district_info.try_borrow_data()?;
+                        District::try_deserialize(&mut &data[..])?.total_votes
+                    }
+                    (Some(contest_id), _) => {
+                        let (expected_contest_pda, _) = Pubkey::find_program_address(
+                            &[
+                                CONTEST_SEED,
+                                election.key().as_ref(),
+                                contest_id.to_le_bytes().as_ref()
+                            ],
+                            program_id
+                        );
+                        require!(
+                            contest_info.key() == expected_contest_pda && contest_info.owner == program_id,
+                            VotingError::InvalidContest
+                        );
+                        let data = contest_info.try_borrow_data()?;
+                        Contest::try_deserialize(&mut &data[..])?.total_votes
+                    }
+                };
+
+                candidate.status != CandidateStatus::Disqualified
+                    && (candidate.vote_count as u128) * 10_000
+                        >= (election.deposit_refund_bps as u128) * (race_votes as u128)
+            }
+            _ => return err!(VotingError::DepositNotSettleable),
+        };
+
+        candidate.deposit_paid = false;
+        write_account(&candidate, candidate_info)?;
+        refunded
+    };
+
+    election.deposits_held = election.deposits_held.saturating_sub(1);
+
+    Ok(refunded)
+}
+
+fn log_settlement(refunded: bool, amount: u64, depositor: &Pubkey, treasury: &Pubkey) {
+    if refunded {
+        msg!("Candidate deposit refunded");
+        msg!("Depositor: {}", depositor);
+    } else {
+        msg!("Candidate deposit forfeited");
+        msg!("Treasury: {}", treasury);
+    }
+    msg!("Amount: {}", amount);
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+use crate::utils::write_account;
+
+// CREATE DISTRICT
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateDistrict<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::DistrictsRequireWhitelist
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = District::SIZE,
+        seeds = [
+            DISTRICT_SEED,
+            election.key().as_ref(),
+            election.district_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub district: Account<'info, District>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_district(ctx: Context<CreateDistrict>, name: String) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // Validate name (required, non-empty)
+    require!(
+        !name.trim().is_empty(),
+        VotingError::InvalidInput
+    );
+    require!(
+        name.len() <= MAX_NAME_LENGTH,
+        VotingError::NameTooLong
+    );
+
+    let district = &mut ctx.accounts.district;
+    let election = &mut ctx.accounts.election;
+
+    district.election = election.key();
+    district.district_id = election.district_count;
+    district.name = name;
+    district.total_votes = 0;
+    district.total_ballots = 0;
+    district.payer = ctx.accounts.authority.key();
+    district.bump = ctx.bumps.district;
+
+    election.district_count = election
+        .district_count
+        .checked_add(1)
+        .ok_or(VotingError::InvalidDistrict)?;
+
+    msg!("District created");
+    msg!("Election: {}", election.title);
+    msg!("District: {}", district.name);
+    msg!("District ID: {}", district.district_id);
+
+    Ok(())
+}
+
+// Applies `update` to the tally of `district_id`, loaded from `district_info`.
+// Elections without districts pass no district and any placeholder account.
+pub(crate) fn update_district_tally(
+    district_info: &AccountInfo,
+    election_key: &Pubkey,
+    district_id: Option<u16>,
+    program_id: &Pubkey,
+    update: impl FnOnce(&mut District),
+) -> Result<()> {
+    let Some(district_id) = district_id else {
+        return Ok(());
+    };
+
+    let (expected_district_pda, _) = Pubkey::find_program_address(
+        &[
+            DISTRICT_SEED,
+            election_key.as_ref(),
+            district_id.to_le_bytes().as_ref()
+        ],
+        program_id
+    );
+    require!(
+        district_info.key() == expected_district_pda,
+        VotingError::InvalidDistrict
+    );
+
+    require!(
+        district_info.owner == program_id,
+        VotingError::InvalidDistrict
+    );
+
+    let mut district = {
+        let data = district_info.try_borrow_data()?;
+        District::try_deserialize(&mut &data[..])?
+    };
+    update(&mut district);
+    write_account(&district, district_info)
+}
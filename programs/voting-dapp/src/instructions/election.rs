@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use solana_sha256_hasher::hashv;
 use crate::constants::*;
 use crate::errors::VotingError;
+use crate::events::ElectionExtended;
+use crate::instructions::party::allocate_party_seats;
+use crate::instructions::vault::{available_lamports, is_election_vault, pay_from_vault};
 use crate::state::*;
+use crate::utils::{close_account, create_pda_account, write_account};
 
 // CREATE ELECTION
 #[derive(Accounts)]
@@ -44,9 +50,96 @@ pub fn create_election(
     start_time: i64,
     end_time: i64,
     voter_registration_type: VoterRegistrationType,
+    vote_weighting: VoteWeighting,
 ) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
 
+    validate_election_details(&title, &description, start_time, end_time)?;
+    
+    let clock = Clock::get()?;
+    
+    // Increment election count first (use current as ID)
+    let election_id = ctx.accounts.admin_registry.election_count;
+    ctx.accounts.admin_registry.election_count = election_id.checked_add(1).unwrap();
+    
+    let election = &mut ctx.accounts.election;
+
+    election.election_id = election_id;
+    election.authority = ctx.accounts.authority.key();
+    election.payer = ctx.accounts.authority.key();
+    election.title = title;
+    election.description = description;
+    election.start_time = start_time;
+    election.end_time = end_time;
+    election.voter_registration_type = voter_registration_type;
+    election.vote_weighting = vote_weighting;
+    election.bump = ctx.bumps.election;
+    set_draft_defaults(election, clock.unix_timestamp);
+    
+    msg!("Election created");
+    msg!("Election ID: {}", election.election_id);
+    msg!("Title: {}", election.title);
+    msg!("Status: Draft");
+    
+    Ok(())
+}
+
+// Resets tallies, counters and lifecycle settings of a new Draft election.
+// Expects end_time to be set already (it bounds the registration window).
+pub(crate) fn set_draft_defaults(election: &mut Election, current_time: i64) {
+    election.registration_start = current_time;
+    election.registration_end = election.end_time;
+    election.voter_roll_frozen = false;
+    election.status = ElectionStatus::Draft;
+    election.total_votes = 0;
+    election.total_ballots = 0;
+    election.candidate_count = 0;
+    election.next_candidate_id = 0;
+    election.max_candidates = DEFAULT_MAX_CANDIDATES;
+    election.withdrawal_policy = WithdrawalPolicy::KeepVotes;
+    election.district_count = 0;
+    election.contest_count = 0;
+    election.party_count = 0;
+    election.registration_count = 0;
+    election.nomination_count = 0;
+    election.party_list_seats = 0;
+    election.seat_allocation = SeatAllocationMethod::DHondt;
+    election.party_votes = 0;
+    election.party_ballots = 0;
+    election.seats_allocated = false;
+    election.auto_start = false;
+    election.crank_bounty = 0;
+    election.extension_count = 0;
+    election.total_extension = 0;
+    election.max_total_extension = DEFAULT_MAX_TOTAL_EXTENSION;
+    election.last_extension_reason = None;
+    election.is_paused = false;
+    election.paused_at = None;
+    election.cloned_from = None;
+    election.series = None;
+    election.previous_edition = None;
+    election.general_election = None;
+    election.promotion_rule = None;
+    election.candidates_promoted = false;
+    election.pending_primaries = 0;
+    election.closed_at = None;
+    election.ballot_seed = None;
+    election.ballot_seed_slot = 0;
+    election.deposit_amount = 0;
+    election.deposit_mint = None;
+    election.deposit_treasury = election.authority;
+    election.deposit_refund_bps = 0;
+    election.deposited_candidates = 0;
+    election.deposits_held = 0;
+}
+
+// Validation shared by create_election and update_election
+pub(crate) fn validate_election_details(
+    title: &str,
+    description: &str,
+    start_time: i64,
+    end_time: i64,
+) -> Result<()> {
     // Validate title (required, non-empty)
     require!(
         !title.trim().is_empty(),
@@ -78,29 +171,458 @@ pub fn create_election(
         start_time > 0 && end_time > 0,
         VotingError::InvalidTimeRange
     );
+
+    Ok(())
+}
+
+// CLONE ELECTION (new Draft election from an existing one)
+//
+// Remaining accounts hold one [source candidate, new candidate PDA] pair for
+// every candidate of the source election, optionally followed by pairs of
+// [source voter registration, new voter registration PDA] for approved
+// voters to carry over. Withdrawn and disqualified candidates, contest
+// candidates and party list candidates are not copied; the new PDA of their
+// pair is ignored. Districts are not cloned, so copied candidates and voters
+// lose their district assignments.
+#[derive(Accounts)]
+pub struct CloneElection<'info> {
+    #[account(
+        mut, // MUTABLE to increment election_count
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        seeds = [ELECTION_SEED, source_election.election_id.to_le_bytes().as_ref()],
+        bump = source_election.bump
+    )]
+    pub source_election: Account<'info, Election>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Election::SIZE,
+        seeds = [ELECTION_SEED, &admin_registry.election_count.to_le_bytes()],
+        bump
+    )]
+    pub election: Account<'info, Election>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn clone_election<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloneElection<'info>>,
+    start_time: i64,
+    end_time: i64,
+    candidate_count: u32,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let source = &ctx.accounts.source_election;
+    let source_key = source.key();
+    
+    validate_election_details(&source.title, &source.description, start_time, end_time)?;
+    require!(
+        candidate_count == source.candidate_count,
+        VotingError::InvalidInput
+    );
+    
+    let candidate_accounts = (candidate_count as usize)
+        .checked_mul(2)
+        .ok_or(VotingError::InvalidInput)?;
+    require!(
+        ctx.remaining_accounts.len() >= candidate_accounts
+            && (ctx.remaining_accounts.len() - candidate_accounts) % 2 == 0,
+        VotingError::InvalidInput
+    );
+    let (candidate_pairs, registration_pairs) = ctx.remaining_accounts.split_at(candidate_accounts);
+    
+    // Carrying voters over is a voter management action
+    if !registration_pairs.is_empty() {
+        require!(
+            ctx.accounts.admin_account.permissions.can_manage_voters,
+            VotingError::InsufficientPermissions
+        );
+    }
+    
+    let clock = Clock::get()?;
+    let authority_info = ctx.accounts.authority.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
     
     // Increment election count first (use current as ID)
     let election_id = ctx.accounts.admin_registry.election_count;
     ctx.accounts.admin_registry.election_count = election_id.checked_add(1).unwrap();
     
     let election = &mut ctx.accounts.election;
-
+    let election_key = election.key();
+    
     election.election_id = election_id;
     election.authority = ctx.accounts.authority.key();
+    election.payer = ctx.accounts.authority.key();
+    election.title = source.title.clone();
+    election.description = source.description.clone();
+    election.start_time = start_time;
+    election.end_time = end_time;
+    election.voter_registration_type = source.voter_registration_type;
+    election.vote_weighting = source.vote_weighting;
+    election.bump = ctx.bumps.election;
+    set_draft_defaults(election, clock.unix_timestamp);
+    election.max_total_extension = source.max_total_extension;
+    election.max_candidates = source.max_candidates;
+    election.withdrawal_policy = source.withdrawal_policy;
+    election.cloned_from = Some(source_key);
+    
+    // Copy candidates
+    let mut seen_sources: Vec<Pubkey> = Vec::with_capacity(candidate_count as usize);
+    for pair in candidate_pairs.chunks(2) {
+        let source_info = &pair[0];
+        let new_info = &pair[1];
+        
+        require!(
+            source_info.owner == ctx.program_id,
+            VotingError::InvalidCandidate
+        );
+        require!(
+            !seen_sources.contains(&source_info.key()),
+            VotingError::InvalidCandidate
+        );
+        
+        let source_candidate = {
+            let data = source_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+        let (expected_source_pda, _) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                source_key.as_ref(),
+                source_candidate.candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            source_info.key() == expected_source_pda,
+            VotingError::InvalidCandidate
+        );
+        seen_sources.push(source_info.key());
+        
+        // Only active main-race candidates carry over
+        if !source_candidate.is_active()
+            || source_candidate.contest_id.is_some()
+            || source_candidate.party_id.is_some()
+        {
+            continue;
+        }
+        
+        require!(
+            election.can_add_candidate(),
+            VotingError::CandidateLimitReached
+        );
+        
+        let candidate_id = election.next_candidate_id;
+        let (new_pda, new_bump) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                election_key.as_ref(),
+                candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(new_info.key() == new_pda, VotingError::InvalidCandidate);
+        
+        create_pda_account(
+            &authority_info,
+            new_info,
+            &system_program_info,
+            Candidate::SIZE,
+            ctx.program_id,
+            &[
+                CANDIDATE_SEED,
+                election_key.as_ref(),
+                candidate_id.to_le_bytes().as_ref(),
+                &[new_bump],
+            ],
+        )?;
+        
+        let candidate = Candidate {
+            election: election_key,
+            candidate_id,
+            name: source_candidate.name,
+            description: source_candidate.description,
+            image_url: source_candidate.image_url,
+            metadata_uri: source_candidate.metadata_uri,
+            metadata_hash: source_candidate.metadata_hash,
+            district_id: None,
+            contest_id: None,
+            party_id: None,
+            list_position: 0,
+            source_candidate: None,
+            wallet: source_candidate.wallet,
+            vote_count: 0,
+            elected: false,
+            deposit_paid: false,
+            status: CandidateStatus::Active,
+            status_changed_at: None,
+            voided_votes: 0,
+            added_by: ctx.accounts.authority.key(),
+            added_at: clock.unix_timestamp,
+            last_updated_at: clock.unix_timestamp,
+            payer: ctx.accounts.authority.key(),
+            bump: new_bump,
+        };
+        write_account(&candidate, new_info)?;
+        
+        election.next_candidate_id = election
+            .next_candidate_id
+            .checked_add(1)
+            .ok_or(VotingError::CandidateLimitReached)?;
+        election.candidate_count = election.candidate_count.saturating_add(1);
+    }
+    
+    // Copy approved voter registrations
+    for pair in registration_pairs.chunks(2) {
+        let source_info = &pair[0];
+        let new_info = &pair[1];
+        
+        require!(
+            source_info.owner == ctx.program_id,
+            VotingError::VoterNotRegistered
+        );
+        let source_registration = {
+            let data = source_info.try_borrow_data()?;
+            VoterRegistration::try_deserialize(&mut &data[..])?
+        };
+        let voter = source_registration.voter;
+        let (expected_source_pda, _) = Pubkey::find_program_address(
+            &[
+                VOTER_REGISTRATION_SEED,
+                source_key.as_ref(),
+                voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            source_info.key() == expected_source_pda,
+            VotingError::VoterNotRegistered
+        );
+        require!(
+            source_registration.status == RegistrationStatus::Approved,
+            VotingError::VoterNotRegistered
+        );
+        
+        let (new_pda, new_bump) = Pubkey::find_program_address(
+            &[
+                VOTER_REGISTRATION_SEED,
+                election_key.as_ref(),
+                voter.as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(new_info.key() == new_pda, VotingError::InvalidInput);
+        require!(new_info.data_is_empty(), VotingError::VoterAlreadyRegistered);
+        
+        create_pda_account(
+            &authority_info,
+            new_info,
+            &system_program_info,
+            VoterRegistration::SIZE,
+            ctx.program_id,
+            &[
+                VOTER_REGISTRATION_SEED,
+                election_key.as_ref(),
+                voter.as_ref(),
+                &[new_bump],
+            ],
+        )?;
+        
+        let registration = clone_registration(
+            election,
+            election_key,
+            &source_registration,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+            new_bump,
+        );
+        write_account(&registration, new_info)?;
+    }
+    
+    msg!("Election cloned");
+    msg!("Source election: {}", source_key);
+    msg!("Election ID: {}", election.election_id);
+    msg!("Candidates copied: {}", election.candidate_count);
+    msg!("Voters copied: {}", registration_pairs.len() / 2);
+    msg!("Status: Draft");
+    
+    Ok(())
+}
+
+// Approved copy of a source registration for a cloned election, counted so
+// delete_election can close it later
+pub(crate) fn clone_registration(
+    election: &mut Election,
+    election_key: Pubkey,
+    source: &VoterRegistration,
+    authority: Pubkey,
+    current_time: i64,
+    bump: u8,
+) -> VoterRegistration {
+    election.registration_count = election.registration_count.saturating_add(1);
+    
+    VoterRegistration {
+        election: election_key,
+        voter: source.voter,
+        status: RegistrationStatus::Approved,
+        requested_at: current_time,
+        approved_at: Some(current_time),
+        approved_by: Some(authority),
+        weight: source.weight,
+        district_id: None,
+        payer: authority,
+        bump,
+    }
+}
+
+// UPDATE ELECTION (Draft only)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateElectionParams {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub voter_registration_type: Option<VoterRegistrationType>,
+    pub max_total_extension: Option<i64>,
+    pub registration_start: Option<i64>,
+    pub registration_end: Option<i64>,
+    pub max_candidates: Option<u32>,
+    pub withdrawal_policy: Option<WithdrawalPolicy>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.can_be_modified() @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn update_election(ctx: Context<UpdateElection>, params: UpdateElectionParams) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    // Check if authority is either the election creator or an admin with
+    // can_manage_elections permission
+    let is_creator = ctx.accounts.authority.key() == ctx.accounts.election.authority;
+    
+    if !is_creator {
+        let authority_key = ctx.accounts.authority.key();
+        let admin_seeds = &[
+            ADMIN_SEED,
+            authority_key.as_ref(),
+        ];
+        let (admin_pda, _) = Pubkey::find_program_address(admin_seeds, ctx.program_id);
+        
+        // Try to load and validate admin account
+        let admin_account_info = ctx.remaining_accounts.first()
+            .ok_or(VotingError::Unauthorized)?;
+        
+        require!(
+            admin_account_info.key() == admin_pda,
+            VotingError::Unauthorized
+        );
+        
+        let admin_data = admin_account_info.try_borrow_data()?;
+        let admin = Admin::try_deserialize(&mut &admin_data[..])?;
+        
+        require!(admin.is_active, VotingError::AdminNotActive);
+        require!(
+            admin.permissions.can_manage_elections,
+            VotingError::InsufficientPermissions
+        );
+    }
+    
+    let election = &mut ctx.accounts.election;
+    
+    let title = params.title.unwrap_or_else(|| election.title.clone());
+    let description = params.description.unwrap_or_else(|| election.description.clone());
+    let start_time = params.start_time.unwrap_or(election.start_time);
+    let end_time = params.end_time.unwrap_or(election.end_time);
+    let voter_registration_type = params
+        .voter_registration_type
+        .unwrap_or(election.voter_registration_type);
+    let max_total_extension = params
+        .max_total_extension
+        .unwrap_or(election.max_total_extension);
+    let registration_start = params
+        .registration_start
+        .unwrap_or(election.registration_start);
+    let registration_end = params
+        .registration_end
+        .unwrap_or(election.registration_end);
+    let max_candidates = params
+        .max_candidates
+        .unwrap_or(election.max_candidates);
+    
+    validate_election_details(&title, &description, start_time, end_time)?;
+    require!(max_total_extension >= 0, VotingError::InvalidInput);
+    
+    // Registration may overlap voting but never outlast it
+    require!(
+        registration_end > registration_start && registration_end <= end_time,
+        VotingError::InvalidTimeRange
+    );
+    
+    // Existing candidates must fit, and every election must stay archivable
+    require!(
+        max_candidates >= election.candidate_count.max(1)
+            && max_candidates <= MAX_ARCHIVED_CANDIDATES,
+        VotingError::CandidateLimitReached
+    );
+    
+    // Districts are assigned through voter registrations
+    require!(
+        !election.has_districts()
+            || voter_registration_type == VoterRegistrationType::Whitelist,
+        VotingError::DistrictsRequireWhitelist
+    );
+    
     election.title = title;
     election.description = description;
     election.start_time = start_time;
     election.end_time = end_time;
-    election.status = ElectionStatus::Draft;
-    election.total_votes = 0;
-    election.candidate_count = 0;
     election.voter_registration_type = voter_registration_type;
-    election.bump = ctx.bumps.election;
+    election.max_total_extension = max_total_extension;
+    election.registration_start = registration_start;
+    election.registration_end = registration_end;
+    election.max_candidates = max_candidates;
+    if let Some(withdrawal_policy) = params.withdrawal_policy {
+        election.withdrawal_policy = withdrawal_policy;
+    }
     
-    msg!("Election created");
+    msg!("Election updated");
     msg!("Election ID: {}", election.election_id);
     msg!("Title: {}", election.title);
-    msg!("Status: Draft");
     
     Ok(())
 }
@@ -130,6 +652,10 @@ pub struct StartElection<'info> {
     )]
     pub election: Account<'info, Election>,
     
+    /// CHECK: SlotHashes sysvar, checked by address. Seeds the ballot order.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    
     pub authority: Signer<'info>,
 }
 
@@ -142,7 +668,16 @@ pub fn start_election(ctx: Context<StartElection>) -> Result<()> {
         election.candidate_count > 0,
         VotingError::InvalidInput
     );
+    require!(
+        !election.has_pending_primaries(),
+        VotingError::PrimariesPending
+    );
+    require!(
+        election.deposits_complete(),
+        VotingError::DepositsOutstanding
+    );
     
+    record_ballot_seed(election, &ctx.accounts.slot_hashes)?;
     election.status = ElectionStatus::Active;
     
     msg!("Election started");
@@ -152,6 +687,27 @@ pub fn start_election(ctx: Context<StartElection>) -> Result<()> {
     Ok(())
 }
 
+// Fixes the ballot order seed from the most recent SlotHashes entry, so the
+// order is known only once the election starts and can be checked against
+// the recorded slot
+fn record_ballot_seed(election: &mut Account<Election>, slot_hashes: &AccountInfo) -> Result<()> {
+    // Layout: entry count (u64), then (slot u64, hash [u8; 32]) newest first
+    let data = slot_hashes.try_borrow_data()?;
+    require!(
+        data.len() >= 48 && data[..8] != [0u8; 8],
+        VotingError::BallotSeedUnavailable
+    );
+    let slot = u64::from_le_bytes(data[8..16].try_into().unwrap());
+    let slot_hash = &data[16..48];
+    
+    election.ballot_seed = Some(hashv(&[slot_hash, election.key().as_ref()]).to_bytes());
+    election.ballot_seed_slot = slot;
+    
+    msg!("Ballot seed slot: {}", slot);
+    
+    Ok(())
+}
+
 // END ELECTION
 #[derive(Accounts)]
 pub struct EndElection<'info> {
@@ -173,7 +729,8 @@ pub struct EndElection<'info> {
         mut,
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
         bump = election.bump,
-        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
     )]
     pub election: Account<'info, Election>,
     
@@ -189,6 +746,7 @@ pub fn end_election(ctx: Context<EndElection>) -> Result<()> {
     msg!("Election ended");
     msg!("Election ID: {}", election.election_id);
     msg!("Total votes: {}", election.total_votes);
+    msg!("Total ballots: {}", election.total_ballots);
     
     Ok(())
 }
@@ -231,6 +789,7 @@ pub fn cancel_election(ctx: Context<CancelElection>) -> Result<()> {
     );
     
     election.status = ElectionStatus::Cancelled;
+    election.closed_at = Some(Clock::get()?.unix_timestamp);
     
     msg!("Election cancelled");
     msg!("Election ID: {}", election.election_id);
@@ -238,7 +797,197 @@ pub fn cancel_election(ctx: Context<CancelElection>) -> Result<()> {
     Ok(())
 }
 
+// DELETE ELECTION (Draft only, not for series editions)
+//
+// Remaining accounts hold pairs of [account, rent recipient] for every
+// candidate, district, contest, party, nomination and voter registration of
+// the election, in any order; each recipient must be the account's payer
+// (the nominee for nominations). The election vault, if created, is closed
+// to the creator.
+#[derive(Accounts)]
+pub struct DeleteElection<'info> {
+    #[account(
+        mut, // MUTABLE to record the deletion
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.can_be_modified() @ VotingError::CannotModifyActiveElection,
+        // A linked primary or general would be left waiting forever
+        constraint = election.general_election.is_none() @ VotingError::InvalidPrimaryLink,
+        constraint = !election.has_pending_primaries() @ VotingError::PrimariesPending,
+        constraint = election.deposits_held == 0 @ VotingError::DepositNotSettled,
+        // The series edition and later editions keep pointing at the election
+        constraint = election.series.is_none() @ VotingError::SeriesEditionNotDeletable
+    )]
+    pub election: Account<'info, Election>,
+    
+    /// CHECK: The election's vault PDA; closed to the creator if it was created.
+    #[account(
+        mut,
+        seeds = [ELECTION_VAULT_SEED, election.key().as_ref()],
+        bump
+    )]
+    pub election_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: Election creator receiving the election's rent.
+    #[account(
+        mut,
+        constraint = creator.key() == election.authority @ VotingError::InvalidRentRecipient
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn delete_election<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeleteElection<'info>>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &ctx.accounts.election;
+    let election_key = election.key();
+    
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        VotingError::ElectionAccountsRemaining
+    );
+    
+    let mut candidates: u32 = 0;
+    let mut districts: u16 = 0;
+    let mut contests: u16 = 0;
+    let mut parties: u16 = 0;
+    let mut nominations: u32 = 0;
+    let mut registrations: u32 = 0;
+    
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let account_info = &pair[0];
+        let recipient_info = &pair[1];
+        
+        require!(
+            account_info.owner == ctx.program_id,
+            VotingError::InvalidElectionAccount
+        );
+        let data = account_info.try_borrow_data()?;
+        let discriminator = data.get(..8).ok_or(VotingError::InvalidElectionAccount)?;
+        
+        // Each account is matched to its PDA and the payer owed its rent
+        let (seed, id, payer): (&[u8], Vec<u8>, Pubkey) = if discriminator == Candidate::DISCRIMINATOR {
+            let candidate = Candidate::try_deserialize(&mut &data[..])?;
+            candidates += 1;
+            (
+                CANDIDATE_SEED,
+                candidate.candidate_id.to_le_bytes().to_vec(),
+                candidate.payer,
+            )
+        } else if discriminator == District::DISCRIMINATOR {
+            let district = District::try_deserialize(&mut &data[..])?;
+            districts += 1;
+            (
+                DISTRICT_SEED,
+                district.district_id.to_le_bytes().to_vec(),
+                district.payer,
+            )
+        } else if discriminator == Contest::DISCRIMINATOR {
+            let contest = Contest::try_deserialize(&mut &data[..])?;
+            contests += 1;
+            (
+                CONTEST_SEED,
+                contest.contest_id.to_le_bytes().to_vec(),
+                contest.payer,
+            )
+        } else if discriminator == Party::DISCRIMINATOR {
+            let party = Party::try_deserialize(&mut &data[..])?;
+            parties += 1;
+            (
+                PARTY_SEED,
+                party.party_id.to_le_bytes().to_vec(),
+                party.payer,
+            )
+        } else if discriminator == Nomination::DISCRIMINATOR {
+            let nomination = Nomination::try_deserialize(&mut &data[..])?;
+            nominations += 1;
+            (
+                NOMINATION_SEED,
+                nomination.nominee.to_bytes().to_vec(),
+                nomination.nominee,
+            )
+        } else if discriminator == VoterRegistration::DISCRIMINATOR {
+            let registration = VoterRegistration::try_deserialize(&mut &data[..])?;
+            registrations += 1;
+            (
+                VOTER_REGISTRATION_SEED,
+                registration.voter.to_bytes().to_vec(),
+                registration.payer,
+            )
+        } else {
+            return err!(VotingError::InvalidElectionAccount);
+        };
+        drop(data);
+        
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[seed, election_key.as_ref(), &id],
+            ctx.program_id
+        );
+        require!(
+            account_info.key() == expected_pda,
+            VotingError::InvalidElectionAccount
+        );
+        require!(
+            recipient_info.key() == payer,
+            VotingError::InvalidRentRecipient
+        );
+        
+        close_account(account_info, recipient_info)?;
+    }
+    
+    // A closed account fails the owner check if passed twice, so matching
+    // counts mean every child account was closed
+    require!(
+        candidates == election.candidate_count
+            && districts == election.district_count
+            && contests == election.contest_count
+            && parties == election.party_count
+            && nominations == election.nomination_count
+            && registrations == election.registration_count,
+        VotingError::ElectionAccountsRemaining
+    );
+    
+    let vault_info = ctx.accounts.election_vault.to_account_info();
+    if is_election_vault(&vault_info, &election_key, ctx.program_id) {
+        close_account(&vault_info, &ctx.accounts.creator.to_account_info())?;
+    }
+    
+    let admin_registry = &mut ctx.accounts.admin_registry;
+    admin_registry.deleted_election_count = admin_registry.deleted_election_count.saturating_add(1);
+    
+    msg!("Election deleted");
+    msg!("Election ID: {}", election.election_id);
+    msg!("Candidates closed: {}", candidates);
+    msg!("Other accounts closed: {}", ctx.remaining_accounts.len() / 2 - candidates as usize);
+    msg!("Deleted elections: {}", admin_registry.deleted_election_count);
+    
+    Ok(())
+}
+
 // FINALIZE ELECTION
+//
+// Elections with a party-list ballot pass every party as remaining accounts
+// so the party-list seats can be allocated.
 #[derive(Accounts)]
 pub struct FinalizeElection<'info> {
     #[account(
@@ -266,15 +1015,418 @@ pub struct FinalizeElection<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn finalize_election(ctx: Context<FinalizeElection>) -> Result<()> {
+pub fn finalize_election<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeElection<'info>>,
+) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
     
     let election = &mut ctx.accounts.election;
+    if election.has_party_list() {
+        allocate_party_seats(election, ctx.remaining_accounts, ctx.program_id)?;
+    }
+    
     election.status = ElectionStatus::Finalized;
+    election.closed_at = Some(Clock::get()?.unix_timestamp);
     
     msg!("Election finalized");
     msg!("Election ID: {}", election.election_id);
     msg!("Total votes: {}", election.total_votes);
+    msg!("Total ballots: {}", election.total_ballots);
+    
+    Ok(())
+}
+
+// EXTEND ELECTION (push end_time of an active election later)
+#[derive(Accounts)]
+pub struct ExtendElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn extend_election(
+    ctx: Context<ExtendElection>,
+    new_end_time: i64,
+    reason: ExtensionReason,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+    
+    // Voting that has already closed cannot be reopened
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    require!(
+        new_end_time > election.end_time,
+        VotingError::ExtensionNotLater
+    );
+    // Pause credits are only granted by resume_election
+    require!(
+        reason != ExtensionReason::PauseCredit,
+        VotingError::InvalidInput
+    );
+    
+    let previous_end_time = election.end_time;
+    require!(
+        election.apply_extension(new_end_time - previous_end_time, reason),
+        VotingError::ExtensionLimitExceeded
+    );
+    
+    emit!(ElectionExtended {
+        election: election.key(),
+        extended_by: ctx.accounts.authority.key(),
+        previous_end_time,
+        new_end_time,
+        extension_count: election.extension_count,
+        total_extension: election.total_extension,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Election extended");
+    msg!("Election ID: {}", election.election_id);
+    msg!("New end time: {}", new_end_time);
+    msg!("Reason: {:?}", reason);
+    
+    Ok(())
+}
+
+// PAUSE ELECTION (halts voting and registration for one election)
+#[derive(Accounts)]
+pub struct PauseElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft 
+            || election.status == ElectionStatus::Active @ VotingError::ElectionEnded,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn pause_election(ctx: Context<PauseElection>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+    
+    election.is_paused = true;
+    election.paused_at = Some(clock.unix_timestamp);
+    
+    msg!("Election paused");
+    msg!("Election ID: {}", election.election_id);
+    msg!("Paused by: {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
+
+// RESUME ELECTION
+#[derive(Accounts)]
+pub struct ResumeElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.is_paused @ VotingError::ElectionNotPaused
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+// When `extend_end_time` is set, the part of the pause that fell inside the
+// voting window is added back to end_time so voters keep the full window.
+// The credit is recorded as a PauseCredit extension (event included) and
+// counts towards max_total_extension like any other extension.
+pub fn resume_election(ctx: Context<ResumeElection>, extend_end_time: bool) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+    
+    let paused_for = election.pause_credit(clock.unix_timestamp);
+    
+    let is_open = matches!(election.status, ElectionStatus::Draft | ElectionStatus::Active);
+    if extend_end_time && is_open && paused_for > 0 {
+        let previous_end_time = election.end_time;
+        require!(
+            election.apply_extension(paused_for, ExtensionReason::PauseCredit),
+            VotingError::ExtensionLimitExceeded
+        );
+        
+        emit!(ElectionExtended {
+            election: election.key(),
+            extended_by: ctx.accounts.authority.key(),
+            previous_end_time,
+            new_end_time: election.end_time,
+            extension_count: election.extension_count,
+            total_extension: election.total_extension,
+            reason: ExtensionReason::PauseCredit,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    election.is_paused = false;
+    election.paused_at = None;
+    
+    msg!("Election resumed");
+    msg!("Election ID: {}", election.election_id);
+    msg!("Paused during voting for: {} seconds", paused_for);
+    msg!("End time: {}", election.end_time);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+// CONFIGURE LIFECYCLE CRANK
+#[derive(Accounts)]
+pub struct ConfigureLifecycleCrank<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.can_be_modified() @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_lifecycle_crank(
+    ctx: Context<ConfigureLifecycleCrank>,
+    auto_start: bool,
+    crank_bounty: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        crank_bounty <= MAX_CRANK_BOUNTY,
+        VotingError::CrankBountyTooLarge
+    );
+    
+    let election = &mut ctx.accounts.election;
+    election.auto_start = auto_start;
+    election.crank_bounty = crank_bounty;
+    
+    msg!("Lifecycle crank configured");
+    msg!("Election ID: {}", election.election_id);
+    msg!("Auto start: {}", auto_start);
+    msg!("Crank bounty: {}", crank_bounty);
+    
+    Ok(())
+}
+
+// CRANK ELECTION (permissionless, time-based lifecycle)
+#[derive(Accounts)]
+pub struct CrankElection<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump
+    )]
+    pub election: Account<'info, Election>,
+    
+    // Election vault paying the crank bounty (optional)
+    /// CHECK: Optional election vault. Seeds/owner validated in crank_election function.
+    #[account(mut)]
+    pub election_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: SlotHashes sysvar, checked by address. Seeds the ballot order on start.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+pub fn crank_election(ctx: Context<CrankElection>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+    
+    // A paused election stays where it is until an admin resumes it
+    require!(!election.is_paused, VotingError::ElectionPaused);
+    
+    match election.status {
+        ElectionStatus::Draft
+            if election.auto_start
+                && clock.unix_timestamp >= election.start_time
+                && election.candidate_count > 0
+                && !election.has_pending_primaries()
+                && election.deposits_complete() =>
+        {
+            record_ballot_seed(election, &ctx.accounts.slot_hashes)?;
+            election.status = ElectionStatus::Active;
+            msg!("Election started by crank");
+        }
+        ElectionStatus::Active if clock.unix_timestamp > election.end_time => {
+            election.status = ElectionStatus::Ended;
+            msg!("Election ended by crank");
+        }
+        _ => return err!(VotingError::NothingToCrank),
+    }
+    
+    msg!("Election ID: {}", election.election_id);
+    
+    // Pay the bounty when the vault can cover it; an empty vault never
+    // blocks the transition
+    let bounty = election.crank_bounty;
+    let vault_info = &ctx.accounts.election_vault;
+    if bounty > 0
+        && is_election_vault(vault_info, &election.key(), ctx.program_id)
+        && available_lamports(vault_info)? >= bounty
+    {
+        pay_from_vault(vault_info, &ctx.accounts.cranker.to_account_info(), bounty)?;
+        msg!("Crank bounty paid: {}", bounty);
+    }
+    
+    Ok(())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::*;
+    use anchor_lang::solana_program::instruction::AccountMeta;
+    use crate::{accounts, instruction};
+
+    fn delete(
+        ledger: &mut TestLedger,
+        election: Pubkey,
+        remaining: &[AccountMeta],
+    ) -> std::result::Result<(), ProgramError> {
+        ledger.process(
+            accounts::DeleteElection {
+                admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                admin_account: pda(&[ADMIN_SEED, SUPER_ADMIN.as_ref()]).0,
+                election,
+                election_vault: pda(&[ELECTION_VAULT_SEED, election.as_ref()]).0,
+                creator: SUPER_ADMIN,
+                authority: SUPER_ADMIN,
+            },
+            instruction::DeleteElection {},
+            remaining,
+        )
+    }
+
+    #[test]
+    fn cloned_registrations_are_closed_with_the_clone() {
+        let mut ledger = TestLedger::new(1_000);
+        setup_admin(&mut ledger);
+
+        // Election 1 is a clone of election 0, which has one approved voter
+        let (source_key, _) = draft_election(0);
+        let (clone_key, mut clone) = draft_election(1);
+        clone.cloned_from = Some(source_key);
+
+        let voter = Pubkey::new_unique();
+        let source_registration = VoterRegistration {
+            election: source_key,
+            voter,
+            status: RegistrationStatus::Approved,
+            requested_at: 0,
+            approved_at: Some(0),
+            approved_by: Some(SUPER_ADMIN),
+            weight: 3,
+            district_id: None,
+            payer: SUPER_ADMIN,
+            bump: 0,
+        };
+        let (registration_key, bump) =
+            pda(&[VOTER_REGISTRATION_SEED, clone_key.as_ref(), voter.as_ref()]);
+        let registration =
+            clone_registration(&mut clone, clone_key, &source_registration, SUPER_ADMIN, 1_000, bump);
+        assert_eq!(clone.registration_count, 1);
+        assert_eq!(registration.election, clone_key);
+        assert_eq!(registration.weight, 3);
+
+        ledger.set_account(clone_key, &clone, Election::SIZE);
+        ledger.set_account(registration_key, &registration, VoterRegistration::SIZE);
+        let rent = ledger.lamports(&clone_key) + ledger.lamports(&registration_key);
+        let balance = ledger.lamports(&SUPER_ADMIN);
+
+        // The copied registration has to be closed with the clone
+        assert_eq!(
+            delete(&mut ledger, clone_key, &[]),
+            Err(error(VotingError::ElectionAccountsRemaining))
+        );
+        assert_eq!(ledger.get::<Election>(&clone_key).registration_count, 1);
+        delete(&mut ledger, clone_key, &[writable(registration_key), writable(SUPER_ADMIN)]).unwrap();
+
+        assert!(!ledger.exists(&clone_key));
+        assert!(!ledger.exists(&registration_key));
+        assert_eq!(ledger.lamports(&SUPER_ADMIN), balance + rent);
+    }
+}
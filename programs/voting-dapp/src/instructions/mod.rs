@@ -1,12 +1,34 @@
 pub mod admin;
+pub mod archive;
 pub mod candidate;
 pub mod cast_vote;
+pub mod contest;
+pub mod delegation;
+pub mod deposit;
+pub mod district;
 pub mod election;
+pub mod nomination;
+pub mod party;
+pub mod primary;
+pub mod series;
+pub mod vault;
+pub mod vote_escrow;
 pub mod voter;
 
 pub use admin::*;
+pub use archive::*;
 pub use candidate::*;
 pub use cast_vote::*;
+pub use contest::*;
+pub use delegation::*;
+pub use deposit::*;
+pub use district::*;
 pub use election::*;
+pub use nomination::*;
+pub use party::*;
+pub use primary::*;
+pub use series::*;
+pub use vault::*;
+pub use vote_escrow::*;
 pub use voter::*;
 
@@ -0,0 +1,429 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::instructions::candidate::validate_candidate_details;
+use crate::state::*;
+use crate::utils::write_account;
+
+// SUBMIT NOMINATION (signed by the nominee, optionally co-signed)
+//
+// An optional nominator is passed as the first remaining account and must
+// sign the transaction.
+#[derive(Accounts)]
+pub struct SubmitNomination<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut, // MUTABLE to count the nomination
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.can_be_modified() @ VotingError::CannotModifyActiveElection,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = nominee,
+        space = Nomination::SIZE,
+        seeds = [
+            NOMINATION_SEED,
+            election.key().as_ref(),
+            nominee.key().as_ref()
+        ],
+        bump
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    #[account(mut)]
+    pub nominee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn submit_nomination(
+    ctx: Context<SubmitNomination>,
+    name: String,
+    description: String,
+    image_url: String,
+    district_id: Option<u16>,
+    contest_id: Option<u16>,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    validate_candidate_details(&name, &description, &image_url)?;
+    require!(
+        ctx.accounts.election.is_valid_district(district_id),
+        VotingError::InvalidDistrict
+    );
+    require!(
+        ctx.accounts.election.is_valid_contest(contest_id),
+        VotingError::InvalidContest
+    );
+
+    // Check the optional co-signing nominator
+    let nominator = match ctx.remaining_accounts.first() {
+        Some(nominator_info) => {
+            require!(
+                nominator_info.is_signer,
+                VotingError::NominatorSignatureMissing
+            );
+            require!(
+                nominator_info.key() != ctx.accounts.nominee.key(),
+                VotingError::InvalidInput
+            );
+            Some(nominator_info.key())
+        }
+        None => None,
+    };
+
+    let nomination = &mut ctx.accounts.nomination;
+    let clock = Clock::get()?;
+
+    nomination.election = ctx.accounts.election.key();
+    nomination.nominee = ctx.accounts.nominee.key();
+    nomination.nominator = nominator;
+    nomination.name = name;
+    nomination.description = description;
+    nomination.image_url = image_url;
+    nomination.district_id = district_id;
+    nomination.contest_id = contest_id;
+    nomination.status = NominationStatus::Pending;
+    nomination.submitted_at = clock.unix_timestamp;
+    nomination.reviewed_at = None;
+    nomination.reviewed_by = None;
+    nomination.candidate = None;
+    nomination.bump = ctx.bumps.nomination;
+
+    let election = &mut ctx.accounts.election;
+    election.nomination_count = election.nomination_count.saturating_add(1);
+
+    msg!("Nomination submitted");
+    msg!("Nominee: {}", ctx.accounts.nomination.nominee);
+    if let Some(nominator) = nominator {
+        msg!("Nominator: {}", nominator);
+    }
+    msg!("Election: {}", ctx.accounts.election.title);
+
+    Ok(())
+}
+
+// APPROVE NOMINATION (creates the candidate)
+#[derive(Accounts)]
+pub struct ApproveNomination<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection,
+        constraint = election.can_add_candidate() @ VotingError::CandidateLimitReached
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            NOMINATION_SEED,
+            election.key().as_ref(),
+            nomination.nominee.as_ref()
+        ],
+        bump = nomination.bump,
+        constraint = nomination.status == NominationStatus::Pending @ VotingError::NominationNotPending
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Candidate::SIZE,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            election.next_candidate_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_nomination(ctx: Context<ApproveNomination>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let nomination = &mut ctx.accounts.nomination;
+    let election = &mut ctx.accounts.election;
+
+    // Districts may have been created since the nomination was submitted
+    require!(
+        election.is_valid_district(nomination.district_id),
+        VotingError::InvalidDistrict
+    );
+    require!(
+        election.is_valid_contest(nomination.contest_id),
+        VotingError::InvalidContest
+    );
+
+    let candidate = &mut ctx.accounts.candidate;
+    let clock = Clock::get()?;
+
+    candidate.election = election.key();
+    candidate.candidate_id = election.next_candidate_id;
+    candidate.name = nomination.name.clone();
+    candidate.description = nomination.description.clone();
+    candidate.image_url = nomination.image_url.clone();
+    candidate.metadata_uri = None;
+    candidate.metadata_hash = None;
+    candidate.district_id = nomination.district_id;
+    candidate.contest_id = nomination.contest_id;
+    candidate.party_id = None;
+    candidate.list_position = 0;
+    candidate.source_candidate = None;
+    candidate.wallet = Some(nomination.nominee);
+    candidate.vote_count = 0;
+    candidate.elected = false;
+    candidate.deposit_paid = false;
+    candidate.status = CandidateStatus::Active;
+    candidate.status_changed_at = None;
+    candidate.voided_votes = 0;
+    candidate.added_by = ctx.accounts.authority.key();
+    candidate.added_at = clock.unix_timestamp;
+    candidate.last_updated_at = clock.unix_timestamp;
+    candidate.payer = ctx.accounts.authority.key();
+    candidate.bump = ctx.bumps.candidate;
+
+    election.next_candidate_id = election
+        .next_candidate_id
+        .checked_add(1)
+        .ok_or(VotingError::CandidateLimitReached)?;
+    election.candidate_count = election.candidate_count.saturating_add(1);
+
+    nomination.status = NominationStatus::Approved;
+    nomination.reviewed_at = Some(clock.unix_timestamp);
+    nomination.reviewed_by = Some(ctx.accounts.authority.key());
+    nomination.candidate = Some(candidate.key());
+
+    msg!("Nomination approved");
+    msg!("Nominee: {}", nomination.nominee);
+    msg!("Candidate: {}", candidate.name);
+    msg!("Candidate ID: {}", candidate.candidate_id);
+
+    Ok(())
+}
+
+// REJECT NOMINATION
+#[derive(Accounts)]
+pub struct RejectNomination<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [
+            NOMINATION_SEED,
+            nomination.election.as_ref(),
+            nomination.nominee.as_ref()
+        ],
+        bump = nomination.bump,
+        constraint = nomination.status == NominationStatus::Pending @ VotingError::NominationNotPending
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn reject_nomination(ctx: Context<RejectNomination>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let nomination = &mut ctx.accounts.nomination;
+    let clock = Clock::get()?;
+
+    nomination.status = NominationStatus::Rejected;
+    nomination.reviewed_at = Some(clock.unix_timestamp);
+    nomination.reviewed_by = Some(ctx.accounts.authority.key());
+
+    msg!("Nomination rejected");
+    msg!("Nominee: {}", nomination.nominee);
+    msg!("Rejected by: {}", ctx.accounts.authority.key());
+
+    Ok(())
+}
+
+// CLOSE NOMINATION (returns rent to the nominee)
+//
+// Rejected nominations can be closed at any time. Any other nomination can
+// be closed once its election is finalized or cancelled, or the election
+// account is gone (deleted, or closed after archival).
+#[derive(Accounts)]
+pub struct CloseNomination<'info> {
+    #[account(
+        mut,
+        close = nominee,
+        seeds = [
+            NOMINATION_SEED,
+            nomination.election.as_ref(),
+            nominee.key().as_ref()
+        ],
+        bump = nomination.bump
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// CHECK: The nomination's election; its nomination count is updated if it still exists.
+    #[account(
+        mut,
+        address = nomination.election @ VotingError::InvalidInput
+    )]
+    pub election: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub nominee: Signer<'info>,
+}
+
+pub fn close_nomination(ctx: Context<CloseNomination>) -> Result<()> {
+    let rejected = ctx.accounts.nomination.status == NominationStatus::Rejected;
+
+    let election_info = ctx.accounts.election.to_account_info();
+    if election_info.owner == ctx.program_id {
+        let mut election = {
+            let data = election_info.try_borrow_data()?;
+            Election::try_deserialize(&mut &data[..])?
+        };
+        require!(
+            rejected || election.is_closed(),
+            VotingError::NominationNotRejected
+        );
+        election.nomination_count = election.nomination_count.saturating_sub(1);
+        write_account(&election, &election_info)?;
+    }
+
+    msg!("Nomination closed");
+    msg!("Nominee: {}", ctx.accounts.nominee.key());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::*;
+    use crate::{accounts, instruction};
+
+    // Pending nomination of a fresh nominee for election 0
+    fn nominate(ledger: &mut TestLedger, status: ElectionStatus) -> (Pubkey, Pubkey, Pubkey) {
+        let (election_key, mut election) = draft_election(0);
+        election.status = status;
+        election.nomination_count = 1;
+        ledger.set_account(election_key, &election, Election::SIZE);
+
+        let nominee = Pubkey::new_unique();
+        let (nomination_key, bump) =
+            pda(&[NOMINATION_SEED, election_key.as_ref(), nominee.as_ref()]);
+        let nomination = Nomination {
+            election: election_key,
+            nominee,
+            nominator: None,
+            name: "Alice".to_string(),
+            description: "Alice for the board".to_string(),
+            image_url: String::new(),
+            district_id: None,
+            contest_id: None,
+            status: NominationStatus::Pending,
+            submitted_at: 0,
+            reviewed_at: None,
+            reviewed_by: None,
+            candidate: None,
+            bump,
+        };
+        ledger.set_account(nomination_key, &nomination, Nomination::SIZE);
+        (election_key, nomination_key, nominee)
+    }
+
+    fn close(
+        ledger: &mut TestLedger,
+        election: Pubkey,
+        nomination: Pubkey,
+        nominee: Pubkey,
+    ) -> std::result::Result<(), ProgramError> {
+        ledger.process(
+            accounts::CloseNomination {
+                nomination,
+                election,
+                nominee,
+            },
+            instruction::CloseNomination {},
+            &[],
+        )
+    }
+
+    #[test]
+    fn open_nominations_stay_until_the_election_closes() {
+        let mut ledger = TestLedger::new(1_000);
+        let (election, nomination, nominee) = nominate(&mut ledger, ElectionStatus::Active);
+
+        assert_eq!(
+            close(&mut ledger, election, nomination, nominee),
+            Err(error(VotingError::NominationNotRejected))
+        );
+        assert!(ledger.exists(&nomination));
+    }
+
+    #[test]
+    fn nominations_close_once_the_election_is_closed() {
+        for status in [ElectionStatus::Finalized, ElectionStatus::Cancelled] {
+            let mut ledger = TestLedger::new(1_000);
+            let (election, nomination, nominee) = nominate(&mut ledger, status);
+            let rent = ledger.lamports(&nomination);
+
+            close(&mut ledger, election, nomination, nominee).unwrap();
+
+            assert!(!ledger.exists(&nomination));
+            assert_eq!(ledger.lamports(&nominee), rent);
+            assert_eq!(ledger.get::<Election>(&election).nomination_count, 0);
+        }
+    }
+
+    #[test]
+    fn nominations_close_once_the_election_account_is_gone() {
+        let mut ledger = TestLedger::new(1_000);
+        let (election, nomination, nominee) = nominate(&mut ledger, ElectionStatus::Finalized);
+        ledger.remove(&election);
+        let rent = ledger.lamports(&nomination);
+
+        close(&mut ledger, election, nomination, nominee).unwrap();
+
+        assert!(!ledger.exists(&nomination));
+        assert_eq!(ledger.lamports(&nominee), rent);
+    }
+}
@@ -0,0 +1,691 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::instructions::cast_vote::voting_power;
+use crate::instructions::vault::{is_election_vault, reimburse_rent};
+use crate::state::*;
+use crate::utils::write_account;
+
+// CONFIGURE PARTY LIST (seats filled from party-list ballots)
+#[derive(Accounts)]
+pub struct ConfigurePartyList<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_party_list(
+    ctx: Context<ConfigurePartyList>,
+    seats: u16,
+    seat_allocation: SeatAllocationMethod,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // Zero seats turns the party-list ballot off
+    require!(
+        seats <= MAX_PARTY_LIST_SEATS,
+        VotingError::InvalidInput
+    );
+
+    let election = &mut ctx.accounts.election;
+    election.party_list_seats = seats;
+    election.seat_allocation = seat_allocation;
+
+    msg!("Party list configured");
+    msg!("Election: {}", election.title);
+    msg!("Seats: {}", election.party_list_seats);
+
+    Ok(())
+}
+
+// CREATE PARTY
+#[derive(Accounts)]
+pub struct CreateParty<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Party::SIZE,
+        seeds = [
+            PARTY_SEED,
+            election.key().as_ref(),
+            election.party_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub party: Account<'info, Party>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_party(ctx: Context<CreateParty>, name: String) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(
+        ctx.accounts.election.party_count < MAX_PARTIES,
+        VotingError::PartyLimitReached
+    );
+
+    // Validate name (required, non-empty)
+    require!(
+        !name.trim().is_empty(),
+        VotingError::InvalidInput
+    );
+    require!(
+        name.len() <= MAX_NAME_LENGTH,
+        VotingError::NameTooLong
+    );
+
+    let party = &mut ctx.accounts.party;
+    let election = &mut ctx.accounts.election;
+
+    party.election = election.key();
+    party.party_id = election.party_count;
+    party.name = name;
+    party.list_size = 0;
+    party.active_list_size = 0;
+    party.vote_count = 0;
+    party.seats_won = 0;
+    party.seats_filled = 0;
+    party.seats_assigned = false;
+    party.payer = ctx.accounts.authority.key();
+    party.bump = ctx.bumps.party;
+
+    election.party_count = election
+        .party_count
+        .checked_add(1)
+        .ok_or(VotingError::InvalidParty)?;
+
+    msg!("Party created");
+    msg!("Election: {}", election.title);
+    msg!("Party: {}", party.name);
+    msg!("Party ID: {}", party.party_id);
+
+    Ok(())
+}
+
+// ADD PARTY CANDIDATE (appends the candidate to the party's ordered list)
+#[derive(Accounts)]
+pub struct AddPartyCandidate<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_candidates @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Draft @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            PARTY_SEED,
+            election.key().as_ref(),
+            party.party_id.to_le_bytes().as_ref()
+        ],
+        bump = party.bump
+    )]
+    pub party: Account<'info, Party>,
+
+    #[account(
+        mut,
+        seeds = [
+            CANDIDATE_SEED,
+            election.key().as_ref(),
+            candidate.candidate_id.to_le_bytes().as_ref()
+        ],
+        bump = candidate.bump,
+        constraint = candidate.party_id.is_none() @ VotingError::CandidateAlreadyInParty
+    )]
+    pub candidate: Account<'info, Candidate>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn add_party_candidate(ctx: Context<AddPartyCandidate>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let party = &mut ctx.accounts.party;
+    let candidate = &mut ctx.accounts.candidate;
+
+    candidate.party_id = Some(party.party_id);
+    candidate.list_position = party.list_size;
+    candidate.last_updated_at = Clock::get()?.unix_timestamp;
+
+    party.list_size = party
+        .list_size
+        .checked_add(1)
+        .ok_or(VotingError::InvalidParty)?;
+    party.active_list_size = party.active_list_size.saturating_add(1);
+
+    msg!("Candidate added to party list");
+    msg!("Party: {}", party.name);
+    msg!("Candidate: {}", candidate.name);
+    msg!("List position: {}", candidate.list_position);
+
+    Ok(())
+}
+
+// CAST PARTY VOTE (party-list ballot)
+//
+// Delegated power is not applied to party-list votes.
+#[derive(Accounts)]
+pub struct CastPartyVote<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Active @ VotingError::ElectionNotActive,
+        constraint = !election.is_paused @ VotingError::ElectionPaused,
+        constraint = election.has_party_list() @ VotingError::PartyListNotEnabled
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            PARTY_SEED,
+            election.key().as_ref(),
+            party.party_id.to_le_bytes().as_ref()
+        ],
+        bump = party.bump
+    )]
+    pub party: Account<'info, Party>,
+
+    /// CHECK: Optional voter registration. For Whitelist elections, seeds/bump/status validated in cast_party_vote function.
+    pub voter_registration: UncheckedAccount<'info>,
+
+    /// CHECK: Optional vote escrow. For VoteEscrow elections, seeds/owner/mint validated in cast_party_vote function.
+    pub vote_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Optional election vault. Seeds/owner validated in cast_party_vote function.
+    #[account(mut)]
+    pub election_vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PartyVoteRecord::SIZE,
+        seeds = [
+            PARTY_VOTE_RECORD_SEED,
+            election.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub party_vote_record: Account<'info, PartyVoteRecord>,
+
+    pub voter: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cast_party_vote(ctx: Context<CastPartyVote>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let election = &mut ctx.accounts.election;
+    let clock = Clock::get()?;
+
+    // Enforce the voting window
+    require!(
+        clock.unix_timestamp <= election.end_time,
+        VotingError::ElectionEnded
+    );
+    require!(
+        election.can_vote(clock.unix_timestamp),
+        VotingError::ElectionNotActive
+    );
+
+    // Party lists are election-wide, so the voter's district does not matter
+    let (weight, _) = voting_power(
+        election,
+        &ctx.accounts.voter.key(),
+        &ctx.accounts.voter_registration,
+        &ctx.accounts.vote_escrow,
+        ctx.program_id,
+        clock.unix_timestamp,
+    )?;
+
+    let sponsored = is_election_vault(&ctx.accounts.election_vault, &election.key(), ctx.program_id);
+    let rent_payer = if sponsored {
+        ctx.accounts.election_vault.key()
+    } else {
+        ctx.accounts.payer.key()
+    };
+
+    let party = &mut ctx.accounts.party;
+    let record = &mut ctx.accounts.party_vote_record;
+
+    record.election = election.key();
+    record.voter = ctx.accounts.voter.key();
+    record.party = party.key();
+    record.weight = weight;
+    record.payer = rent_payer;
+    record.voted_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.party_vote_record;
+
+    if sponsored {
+        reimburse_rent(
+            &ctx.accounts.election_vault,
+            &ctx.accounts.payer.to_account_info(),
+            PartyVoteRecord::SIZE,
+            1,
+        )?;
+    }
+
+    party.vote_count = party.vote_count.saturating_add(weight);
+    election.party_votes = election.party_votes.saturating_add(weight);
+    election.party_ballots = election.party_ballots.saturating_add(1);
+
+    msg!("Party vote cast successfully");
+    msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("Party: {}", party.name);
+    msg!("Vote weight: {}", weight);
+    msg!("Party vote count: {}", party.vote_count);
+
+    Ok(())
+}
+
+// Allocates the election's party-list seats when it is finalized.
+// `party_infos` must hold every party of the election.
+pub(crate) fn allocate_party_seats(
+    election: &mut Account<Election>,
+    party_infos: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    require!(
+        party_infos.len() == election.party_count as usize,
+        VotingError::IncompletePartyList
+    );
+
+    let election_key = election.key();
+    let mut parties: Vec<Party> = Vec::with_capacity(party_infos.len());
+    for party_info in party_infos.iter() {
+        require!(
+            party_info.owner == program_id,
+            VotingError::InvalidParty
+        );
+        let party = {
+            let data = party_info.try_borrow_data()?;
+            Party::try_deserialize(&mut &data[..])?
+        };
+        let (expected_party_pda, _) = Pubkey::find_program_address(
+            &[
+                PARTY_SEED,
+                election_key.as_ref(),
+                party.party_id.to_le_bytes().as_ref()
+            ],
+            program_id
+        );
+        require!(
+            party_info.key() == expected_party_pda,
+            VotingError::InvalidParty
+        );
+        require!(
+            !parties.iter().any(|seen| seen.party_id == party.party_id),
+            VotingError::IncompletePartyList
+        );
+        parties.push(party);
+    }
+
+    let votes: Vec<u64> = parties.iter().map(|party| party.vote_count).collect();
+    // Withdrawn and disqualified candidates cannot take a seat
+    let list_sizes: Vec<u16> = parties.iter().map(|party| party.active_list_size).collect();
+    let seats = highest_averages(&votes, &list_sizes, election.party_list_seats, election.seat_allocation);
+
+    for ((party, party_info), seats_won) in parties.iter_mut().zip(party_infos.iter()).zip(seats) {
+        party.seats_won = seats_won;
+        write_account(party, party_info)?;
+
+        msg!("Party: {}", party.name);
+        msg!("Votes: {}", party.vote_count);
+        msg!("Seats: {}", party.seats_won);
+    }
+
+    election.seats_allocated = true;
+
+    Ok(())
+}
+
+// Seats per party under a highest-averages method. Each seat goes to the
+// party with the largest votes / divisor quotient; ties go to the party
+// listed first. Parties without votes win no seats, and a party whose list
+// is exhausted is skipped, so seats can stay unallocated.
+fn highest_averages(votes: &[u64], list_sizes: &[u16], seats: u16, method: SeatAllocationMethod) -> Vec<u16> {
+    let mut won = vec![0u16; votes.len()];
+
+    for _ in 0..seats {
+        let mut best: Option<usize> = None;
+        for (index, party_votes) in votes.iter().enumerate() {
+            if *party_votes == 0 || won[index] >= list_sizes[index] {
+                continue;
+            }
+            let is_better = match best {
+                None => true,
+                Some(best_index) => {
+                    // votes[i] / d[i] > votes[b] / d[b], compared without division
+                    (*party_votes as u128) * (method.divisor(won[best_index]) as u128)
+                        > (votes[best_index] as u128) * (method.divisor(won[index]) as u128)
+                }
+            };
+            if is_better {
+                best = Some(index);
+            }
+        }
+
+        match best {
+            Some(index) => won[index] += 1,
+            None => break,
+        }
+    }
+
+    won
+}
+
+// ASSIGN PARTY SEATS (permissionless, once the election is finalized)
+//
+// Remaining accounts must hold every candidate on the party's list. Seats go
+// to active candidates in list order.
+#[derive(Accounts)]
+pub struct AssignPartySeats<'info> {
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Finalized @ VotingError::InvalidInput,
+        constraint = election.seats_allocated @ VotingError::PartyListNotEnabled
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [
+            PARTY_SEED,
+            election.key().as_ref(),
+            party.party_id.to_le_bytes().as_ref()
+        ],
+        bump = party.bump,
+        constraint = !party.seats_assigned @ VotingError::SeatsAlreadyAssigned
+    )]
+    pub party: Account<'info, Party>,
+}
+
+pub fn assign_party_seats<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AssignPartySeats<'info>>,
+) -> Result<()> {
+    let election_key = ctx.accounts.election.key();
+    let party = &mut ctx.accounts.party;
+
+    require!(
+        ctx.remaining_accounts.len() == party.list_size as usize,
+        VotingError::IncompleteCandidateList
+    );
+
+    // Load the party list
+    let mut list: Vec<(&AccountInfo<'info>, Candidate)> = Vec::with_capacity(ctx.remaining_accounts.len());
+    for candidate_info in ctx.remaining_accounts.iter() {
+        require!(
+            candidate_info.owner == ctx.program_id,
+            VotingError::InvalidCandidate
+        );
+        let candidate = {
+            let data = candidate_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+        let (expected_candidate_pda, _) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                election_key.as_ref(),
+                candidate.candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            candidate_info.key() == expected_candidate_pda,
+            VotingError::InvalidCandidate
+        );
+        require!(
+            candidate.party_id == Some(party.party_id),
+            VotingError::InvalidParty
+        );
+        require!(
+            !list.iter().any(|(_, listed)| listed.list_position == candidate.list_position),
+            VotingError::IncompleteCandidateList
+        );
+        list.push((candidate_info, candidate));
+    }
+
+    list.sort_by_key(|(_, candidate)| candidate.list_position);
+
+    let mut seats_filled: u16 = 0;
+    for (candidate_info, mut candidate) in list.into_iter() {
+        if seats_filled == party.seats_won {
+            break;
+        }
+        if !candidate.is_active() {
+            continue;
+        }
+
+        candidate.elected = true;
+        write_account(&candidate, candidate_info)?;
+        seats_filled += 1;
+
+        msg!("Elected: {}", candidate.name);
+    }
+
+    party.seats_filled = seats_filled;
+    party.seats_assigned = true;
+
+    msg!("Party seats assigned");
+    msg!("Party: {}", party.name);
+    msg!("Seats won: {}", party.seats_won);
+    msg!("Seats filled: {}", party.seats_filled);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::*;
+    use crate::{accounts, instruction};
+
+    const UNLIMITED: [u16; 4] = [u16::MAX; 4];
+
+    #[test]
+    fn dhondt_matches_reference_tables() {
+        let seats = highest_averages(&[100_000, 80_000, 30_000, 20_000], &UNLIMITED, 8, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![4, 3, 1, 0]);
+
+        let seats = highest_averages(&[53_000, 24_000, 23_000], &UNLIMITED[..3], 7, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![4, 2, 1]);
+    }
+
+    #[test]
+    fn sainte_lague_matches_reference_tables() {
+        let seats = highest_averages(&[100_000, 80_000, 30_000, 20_000], &UNLIMITED, 8, SeatAllocationMethod::SainteLague);
+        assert_eq!(seats, vec![3, 3, 1, 1]);
+
+        let seats = highest_averages(&[53_000, 24_000, 23_000], &UNLIMITED[..3], 7, SeatAllocationMethod::SainteLague);
+        assert_eq!(seats, vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn ties_go_to_the_party_listed_first() {
+        let seats = highest_averages(&[100, 100], &UNLIMITED[..2], 1, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![1, 0]);
+
+        let seats = highest_averages(&[100, 100], &UNLIMITED[..2], 3, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![2, 1]);
+    }
+
+    #[test]
+    fn parties_without_votes_win_nothing() {
+        let seats = highest_averages(&[0, 0, 10], &UNLIMITED[..3], 3, SeatAllocationMethod::SainteLague);
+        assert_eq!(seats, vec![0, 0, 3]);
+
+        let seats = highest_averages(&[0, 0], &UNLIMITED[..2], 3, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![0, 0]);
+    }
+
+    #[test]
+    fn exhausted_lists_are_skipped() {
+        let seats = highest_averages(&[1_000, 10], &[2, 5], 5, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![2, 3]);
+    }
+
+    #[test]
+    fn seats_beyond_every_list_stay_unallocated() {
+        let seats = highest_averages(&[1_000, 0], &[2, 5], 5, SeatAllocationMethod::DHondt);
+        assert_eq!(seats, vec![2, 0]);
+
+        let seats = highest_averages(&[300, 200], &[1, 1], 4, SeatAllocationMethod::SainteLague);
+        assert_eq!(seats, vec![1, 1]);
+    }
+
+    fn party(election: Pubkey, party_id: u16, vote_count: u64, list_size: u16) -> (Pubkey, Party) {
+        let (key, bump) = pda(&[PARTY_SEED, election.as_ref(), &party_id.to_le_bytes()]);
+        let party = Party {
+            election,
+            party_id,
+            name: format!("Party {party_id}"),
+            list_size,
+            active_list_size: list_size,
+            vote_count,
+            seats_won: 0,
+            seats_filled: 0,
+            seats_assigned: false,
+            payer: SUPER_ADMIN,
+            bump,
+        };
+        (key, party)
+    }
+
+    #[test]
+    fn withdrawn_list_candidates_do_not_count_toward_seats() {
+        let mut ledger = TestLedger::new(1_000);
+        setup_admin(&mut ledger);
+
+        let (election_key, mut election) = draft_election(0);
+        election.status = ElectionStatus::Active;
+        election.end_time = 10_000;
+        election.party_count = 2;
+        election.party_list_seats = 3;
+        election.seat_allocation = SeatAllocationMethod::DHondt;
+        ledger.set_account(election_key, &election, Election::SIZE);
+
+        // Party 0 has two listed candidates, party 1 has three
+        let (first_key, first) = party(election_key, 0, 1_000, 2);
+        let (second_key, second) = party(election_key, 1, 10, 3);
+        ledger.set_account(first_key, &first, Party::SIZE);
+        ledger.set_account(second_key, &second, Party::SIZE);
+
+        let wallet = Pubkey::new_unique();
+        let (candidate_key, mut candidate) = active_candidate(election_key, 1);
+        candidate.party_id = Some(0);
+        candidate.list_position = 1;
+        candidate.wallet = Some(wallet);
+        ledger.set_account(candidate_key, &candidate, Candidate::SIZE);
+
+        ledger
+            .process(
+                accounts::WithdrawCandidate {
+                    admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                    election: election_key,
+                    candidate: candidate_key,
+                    district: Pubkey::new_unique(),
+                    contest: Pubkey::new_unique(),
+                    party: first_key,
+                    wallet,
+                },
+                instruction::WithdrawCandidate {},
+                &[],
+            )
+            .unwrap();
+        assert_eq!(ledger.get::<Party>(&first_key).active_list_size, 1);
+
+        let mut election = ledger.get::<Election>(&election_key);
+        election.status = ElectionStatus::Ended;
+        ledger.set_account(election_key, &election, Election::SIZE);
+
+        ledger
+            .process(
+                accounts::FinalizeElection {
+                    admin_registry: pda(&[ADMIN_REGISTRY_SEED]).0,
+                    admin_account: pda(&[ADMIN_SEED, SUPER_ADMIN.as_ref()]).0,
+                    election: election_key,
+                    authority: SUPER_ADMIN,
+                },
+                instruction::FinalizeElection {},
+                &[writable(first_key), writable(second_key)],
+            )
+            .unwrap();
+
+        // Party 0 would take two seats with its original list
+        assert_eq!(ledger.get::<Party>(&first_key).seats_won, 1);
+        assert_eq!(ledger.get::<Party>(&second_key).seats_won, 2);
+    }
+}
@@ -0,0 +1,337 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+use crate::utils::{create_pda_account, write_account};
+
+// LINK PRIMARY (feed a primary's results into a general election)
+#[derive(Accounts)]
+pub struct LinkPrimary<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, primary_election.election_id.to_le_bytes().as_ref()],
+        bump = primary_election.bump,
+        constraint = primary_election.can_be_modified() @ VotingError::CannotModifyActiveElection,
+        constraint = primary_election.general_election.is_none() @ VotingError::InvalidPrimaryLink
+    )]
+    pub primary_election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, general_election.election_id.to_le_bytes().as_ref()],
+        bump = general_election.bump,
+        constraint = general_election.can_be_modified() @ VotingError::CannotModifyActiveElection,
+        constraint = general_election.key() != primary_election.key() @ VotingError::InvalidPrimaryLink,
+        constraint = general_election.general_election != Some(primary_election.key()) @ VotingError::InvalidPrimaryLink
+    )]
+    pub general_election: Account<'info, Election>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn link_primary(ctx: Context<LinkPrimary>, promotion_rule: PromotionRule) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    match promotion_rule {
+        PromotionRule::TopN { count } => {
+            require!(
+                count > 0,
+                VotingError::InvalidInput
+            );
+        }
+        PromotionRule::MinimumShare { basis_points } => {
+            require!(
+                basis_points > 0 && basis_points <= 10_000,
+                VotingError::InvalidInput
+            );
+        }
+    }
+
+    let general_key = ctx.accounts.general_election.key();
+    let primary = &mut ctx.accounts.primary_election;
+    primary.general_election = Some(general_key);
+    primary.promotion_rule = Some(promotion_rule);
+
+    let general = &mut ctx.accounts.general_election;
+    general.pending_primaries = general
+        .pending_primaries
+        .checked_add(1)
+        .ok_or(VotingError::InvalidPrimaryLink)?;
+
+    msg!("Primary linked");
+    msg!("Primary: {}", primary.title);
+    msg!("General: {}", general.title);
+    msg!("Pending primaries: {}", general.pending_primaries);
+
+    Ok(())
+}
+
+// UNLINK PRIMARY (e.g. a cancelled primary that will never be promoted)
+#[derive(Accounts)]
+pub struct UnlinkPrimary<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, primary_election.election_id.to_le_bytes().as_ref()],
+        bump = primary_election.bump,
+        constraint = primary_election.general_election == Some(general_election.key()) @ VotingError::InvalidPrimaryLink,
+        constraint = !primary_election.candidates_promoted @ VotingError::CandidatesAlreadyPromoted
+    )]
+    pub primary_election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, general_election.election_id.to_le_bytes().as_ref()],
+        bump = general_election.bump
+    )]
+    pub general_election: Account<'info, Election>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn unlink_primary(ctx: Context<UnlinkPrimary>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let primary = &mut ctx.accounts.primary_election;
+    primary.general_election = None;
+    primary.promotion_rule = None;
+
+    // The general election no longer waits for this primary
+    let general = &mut ctx.accounts.general_election;
+    general.pending_primaries = general.pending_primaries.saturating_sub(1);
+
+    msg!("Primary unlinked");
+    msg!("Primary: {}", primary.title);
+    msg!("General: {}", general.title);
+    msg!("Pending primaries: {}", general.pending_primaries);
+
+    Ok(())
+}
+
+// PROMOTE PRIMARY CANDIDATES (permissionless, once the primary is finalized)
+//
+// Remaining accounts hold every candidate of the primary, followed by one
+// new candidate PDA on the general election per promoted candidate. The
+// promoted candidates are taken in ranking order: most votes first, ties
+// broken by the lower candidate ID.
+#[derive(Accounts)]
+pub struct PromotePrimaryCandidates<'info> {
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, primary_election.election_id.to_le_bytes().as_ref()],
+        bump = primary_election.bump,
+        constraint = primary_election.status == ElectionStatus::Finalized @ VotingError::InvalidInput,
+        constraint = primary_election.general_election == Some(general_election.key()) @ VotingError::InvalidPrimaryLink,
+        constraint = !primary_election.candidates_promoted @ VotingError::CandidatesAlreadyPromoted
+    )]
+    pub primary_election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, general_election.election_id.to_le_bytes().as_ref()],
+        bump = general_election.bump,
+        constraint = general_election.can_be_modified() @ VotingError::CannotModifyActiveElection
+    )]
+    pub general_election: Account<'info, Election>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn promote_primary_candidates<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PromotePrimaryCandidates<'info>>,
+) -> Result<()> {
+    let primary_key = ctx.accounts.primary_election.key();
+    let general_key = ctx.accounts.general_election.key();
+    let primary_candidates = ctx.accounts.primary_election.candidate_count as usize;
+
+    require!(
+        ctx.remaining_accounts.len() >= primary_candidates,
+        VotingError::IncompleteCandidateList
+    );
+    let (candidate_infos, new_candidate_infos) = ctx.remaining_accounts.split_at(primary_candidates);
+
+    // Load every main-race candidate of the primary
+    let mut ranked: Vec<(Pubkey, Candidate)> = Vec::with_capacity(primary_candidates);
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(primary_candidates);
+    for candidate_info in candidate_infos.iter() {
+        require!(
+            candidate_info.owner == ctx.program_id,
+            VotingError::InvalidCandidate
+        );
+        require!(
+            !seen.contains(&candidate_info.key()),
+            VotingError::IncompleteCandidateList
+        );
+
+        let candidate = {
+            let data = candidate_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+        let (expected_candidate_pda, _) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                primary_key.as_ref(),
+                candidate.candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            candidate_info.key() == expected_candidate_pda,
+            VotingError::InvalidCandidate
+        );
+        seen.push(candidate_info.key());
+
+        // Contest and inactive candidates are not promoted
+        if candidate.contest_id.is_none() && candidate.is_active() {
+            ranked.push((candidate_info.key(), candidate));
+        }
+    }
+
+    ranked.sort_by(|(_, a), (_, b)| {
+        b.vote_count
+            .cmp(&a.vote_count)
+            .then(a.candidate_id.cmp(&b.candidate_id))
+    });
+
+    let primary_total_votes = ctx.accounts.primary_election.total_votes;
+    let promotion_rule = ctx
+        .accounts
+        .primary_election
+        .promotion_rule
+        .ok_or(VotingError::InvalidPrimaryLink)?;
+    let promoted_count = match promotion_rule {
+        PromotionRule::TopN { count } => ranked.len().min(count as usize),
+        PromotionRule::MinimumShare { basis_points } => ranked
+            .iter()
+            .filter(|(_, candidate)| {
+                (candidate.vote_count as u128) * 10_000
+                    >= (basis_points as u128) * (primary_total_votes as u128)
+                    && candidate.vote_count > 0
+            })
+            .count(),
+    };
+
+    require!(
+        new_candidate_infos.len() == promoted_count,
+        VotingError::IncompleteCandidateList
+    );
+
+    let clock = Clock::get()?;
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    let general = &mut ctx.accounts.general_election;
+
+    for ((source_key, source), new_info) in ranked.into_iter().zip(new_candidate_infos.iter()) {
+        // Districts belong to each election; the assignment only carries
+        // over when the general election has the same district
+        require!(
+            general.is_valid_district(source.district_id),
+            VotingError::InvalidDistrict
+        );
+
+        require!(
+            general.can_add_candidate(),
+            VotingError::CandidateLimitReached
+        );
+
+        let candidate_id = general.next_candidate_id;
+        let (new_pda, new_bump) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                general_key.as_ref(),
+                candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(new_info.key() == new_pda, VotingError::InvalidCandidate);
+
+        create_pda_account(
+            &payer_info,
+            new_info,
+            &system_program_info,
+            Candidate::SIZE,
+            ctx.program_id,
+            &[
+                CANDIDATE_SEED,
+                general_key.as_ref(),
+                candidate_id.to_le_bytes().as_ref(),
+                &[new_bump],
+            ],
+        )?;
+
+        let candidate = Candidate {
+            election: general_key,
+            candidate_id,
+            name: source.name,
+            description: source.description,
+            image_url: source.image_url,
+            metadata_uri: source.metadata_uri,
+            metadata_hash: source.metadata_hash,
+            district_id: source.district_id,
+            contest_id: None,
+            party_id: None,
+            list_position: 0,
+            source_candidate: Some(source_key),
+            wallet: source.wallet,
+            vote_count: 0,
+            elected: false,
+            deposit_paid: false,
+            status: CandidateStatus::Active,
+            status_changed_at: None,
+            voided_votes: 0,
+            added_by: ctx.accounts.payer.key(),
+            added_at: clock.unix_timestamp,
+            last_updated_at: clock.unix_timestamp,
+            payer: ctx.accounts.payer.key(),
+            bump: new_bump,
+        };
+        write_account(&candidate, new_info)?;
+
+        general.next_candidate_id = general
+            .next_candidate_id
+            .checked_add(1)
+            .ok_or(VotingError::CandidateLimitReached)?;
+        general.candidate_count = general.candidate_count.saturating_add(1);
+    }
+
+    general.pending_primaries = general.pending_primaries.saturating_sub(1);
+    ctx.accounts.primary_election.candidates_promoted = true;
+
+    msg!("Primary candidates promoted");
+    msg!("Primary: {}", primary_key);
+    msg!("General: {}", general.title);
+    msg!("Promoted: {}", promoted_count);
+    msg!("Pending primaries: {}", general.pending_primaries);
+
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::instructions::election::{set_draft_defaults, validate_election_details};
+use crate::state::*;
+
+// CREATE SERIES
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct CreateSeries<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Series::SIZE,
+        seeds = [
+            SERIES_SEED,
+            authority.key().as_ref(),
+            series_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_series(
+    ctx: Context<CreateSeries>,
+    series_id: u64,
+    name: String,
+    description: String,
+    voting_duration: i64,
+    voter_registration_type: VoterRegistrationType,
+    vote_weighting: VoteWeighting,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    // Validate name (required, non-empty)
+    require!(
+        !name.trim().is_empty(),
+        VotingError::InvalidInput
+    );
+    require!(
+        name.len() <= MAX_NAME_LENGTH,
+        VotingError::NameTooLong
+    );
+
+    // Validate description (required, non-empty)
+    require!(
+        !description.trim().is_empty(),
+        VotingError::InvalidInput
+    );
+    require!(
+        description.len() <= MAX_DESCRIPTION_LENGTH,
+        VotingError::DescriptionTooLong
+    );
+
+    require!(voting_duration > 0, VotingError::InvalidTimeRange);
+
+    let series = &mut ctx.accounts.series;
+
+    series.authority = ctx.accounts.authority.key();
+    series.series_id = series_id;
+    series.name = name;
+    series.description = description;
+    series.voting_duration = voting_duration;
+    series.voter_registration_type = voter_registration_type;
+    series.vote_weighting = vote_weighting;
+    series.max_total_extension = DEFAULT_MAX_TOTAL_EXTENSION;
+    series.edition_count = 0;
+    series.latest_election = None;
+    series.recorded_editions = 0;
+    series.total_votes = 0;
+    series.total_ballots = 0;
+    series.bump = ctx.bumps.series;
+
+    msg!("Series created");
+    msg!("Series: {}", series.name);
+    msg!("Series ID: {}", series.series_id);
+
+    Ok(())
+}
+
+// CREATE SERIES ELECTION (next edition, using the series defaults)
+#[derive(Accounts)]
+pub struct CreateSeriesElection<'info> {
+    #[account(
+        mut, // MUTABLE to increment election_count
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_elections @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [
+            SERIES_SEED,
+            series.authority.as_ref(),
+            series.series_id.to_le_bytes().as_ref()
+        ],
+        bump = series.bump,
+        has_one = authority @ VotingError::Unauthorized
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Election::SIZE,
+        seeds = [ELECTION_SEED, &admin_registry.election_count.to_le_bytes()],
+        bump
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeriesEdition::SIZE,
+        seeds = [
+            SERIES_EDITION_SEED,
+            series.key().as_ref(),
+            series.edition_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub series_edition: Account<'info, SeriesEdition>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_series_election(ctx: Context<CreateSeriesElection>, start_time: i64) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let series = &mut ctx.accounts.series;
+    let edition_number = series.edition_count;
+    let title = format!("{} #{}", series.name, edition_number.saturating_add(1));
+    let end_time = start_time
+        .checked_add(series.voting_duration)
+        .ok_or(VotingError::InvalidTimeRange)?;
+
+    validate_election_details(&title, &series.description, start_time, end_time)?;
+
+    let clock = Clock::get()?;
+
+    // Increment election count first (use current as ID)
+    let election_id = ctx.accounts.admin_registry.election_count;
+    ctx.accounts.admin_registry.election_count = election_id.checked_add(1).unwrap();
+
+    let election = &mut ctx.accounts.election;
+    let election_key = election.key();
+
+    election.election_id = election_id;
+    election.authority = ctx.accounts.authority.key();
+    election.payer = ctx.accounts.authority.key();
+    election.title = title;
+    election.description = series.description.clone();
+    election.start_time = start_time;
+    election.end_time = end_time;
+    election.voter_registration_type = series.voter_registration_type;
+    election.vote_weighting = series.vote_weighting;
+    election.bump = ctx.bumps.election;
+    set_draft_defaults(election, clock.unix_timestamp);
+    election.max_total_extension = series.max_total_extension;
+    election.series = Some(series.key());
+    election.previous_edition = series.latest_election;
+
+    let series_edition = &mut ctx.accounts.series_edition;
+
+    series_edition.series = series.key();
+    series_edition.edition = edition_number;
+    series_edition.election = election_key;
+    series_edition.previous_election = series.latest_election;
+    series_edition.results_recorded = false;
+    series_edition.total_votes = 0;
+    series_edition.total_ballots = 0;
+    series_edition.winner = None;
+    series_edition.winner_votes = 0;
+    series_edition.bump = ctx.bumps.series_edition;
+
+    series.edition_count = series
+        .edition_count
+        .checked_add(1)
+        .ok_or(VotingError::InvalidSeriesEdition)?;
+    series.latest_election = Some(election_key);
+
+    msg!("Series election created");
+    msg!("Series: {}", series.name);
+    msg!("Edition: {}", series_edition.edition);
+    msg!("Election ID: {}", election.election_id);
+    msg!("Status: Draft");
+
+    Ok(())
+}
+
+// RECORD EDITION RESULTS (permissionless, once the election is finalized)
+//
+// Remaining accounts must hold every candidate of the election so the
+// winner of the main race can be determined.
+#[derive(Accounts)]
+pub struct RecordEditionResults<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SERIES_SEED,
+            series.authority.as_ref(),
+            series.series_id.to_le_bytes().as_ref()
+        ],
+        bump = series.bump
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [
+            SERIES_EDITION_SEED,
+            series.key().as_ref(),
+            series_edition.edition.to_le_bytes().as_ref()
+        ],
+        bump = series_edition.bump,
+        constraint = series_edition.election == election.key() @ VotingError::InvalidSeriesEdition,
+        constraint = !series_edition.results_recorded @ VotingError::EditionResultsAlreadyRecorded
+    )]
+    pub series_edition: Account<'info, SeriesEdition>,
+
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.status == ElectionStatus::Finalized @ VotingError::InvalidInput
+    )]
+    pub election: Account<'info, Election>,
+}
+
+pub fn record_edition_results<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordEditionResults<'info>>,
+) -> Result<()> {
+    let election = &ctx.accounts.election;
+    let election_key = election.key();
+
+    require!(
+        ctx.remaining_accounts.len() == election.candidate_count as usize,
+        VotingError::IncompleteCandidateList
+    );
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut winner: Option<Pubkey> = None;
+    let mut winner_votes: u64 = 0;
+    let mut tied = false;
+
+    for candidate_info in ctx.remaining_accounts.iter() {
+        require!(
+            candidate_info.owner == ctx.program_id,
+            VotingError::InvalidCandidate
+        );
+        require!(
+            !seen.contains(&candidate_info.key()),
+            VotingError::IncompleteCandidateList
+        );
+
+        let candidate = {
+            let data = candidate_info.try_borrow_data()?;
+            Candidate::try_deserialize(&mut &data[..])?
+        };
+        let (expected_candidate_pda, _) = Pubkey::find_program_address(
+            &[
+                CANDIDATE_SEED,
+                election_key.as_ref(),
+                candidate.candidate_id.to_le_bytes().as_ref()
+            ],
+            ctx.program_id
+        );
+        require!(
+            candidate_info.key() == expected_candidate_pda,
+            VotingError::InvalidCandidate
+        );
+        seen.push(candidate_info.key());
+
+        // Contest and inactive candidates cannot win the main race
+        if candidate.contest_id.is_some() || !candidate.is_active() {
+            continue;
+        }
+
+        if winner.is_none() || candidate.vote_count > winner_votes {
+            winner = Some(candidate_info.key());
+            winner_votes = candidate.vote_count;
+            tied = false;
+        } else if candidate.vote_count == winner_votes {
+            tied = true;
+        }
+    }
+
+    let series_edition = &mut ctx.accounts.series_edition;
+    series_edition.results_recorded = true;
+    series_edition.total_votes = election.total_votes;
+    series_edition.total_ballots = election.total_ballots;
+    series_edition.winner = if tied { None } else { winner };
+    series_edition.winner_votes = winner_votes;
+
+    let series = &mut ctx.accounts.series;
+    series.recorded_editions = series.recorded_editions.saturating_add(1);
+    series.total_votes = series.total_votes.saturating_add(election.total_votes);
+    series.total_ballots = series.total_ballots.saturating_add(election.total_ballots);
+
+    msg!("Edition results recorded");
+    msg!("Series: {}", series.name);
+    msg!("Edition: {}", series_edition.edition);
+    msg!("Total ballots: {}", series_edition.total_ballots);
+    match series_edition.winner {
+        Some(winner) => msg!("Winner: {}", winner),
+        None => msg!("Winner: none (tie or no candidates)"),
+    }
+
+    Ok(())
+}
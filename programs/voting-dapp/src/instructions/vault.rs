@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+use crate::utils::write_account;
+
+// CREATE ELECTION VAULT
+#[derive(Accounts)]
+pub struct CreateElectionVault<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        has_one = authority @ VotingError::Unauthorized
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ElectionVault::SIZE,
+        seeds = [ELECTION_VAULT_SEED, election.key().as_ref()],
+        bump
+    )]
+    pub election_vault: Account<'info, ElectionVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_election_vault(ctx: Context<CreateElectionVault>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    let election_vault = &mut ctx.accounts.election_vault;
+
+    election_vault.election = ctx.accounts.election.key();
+    election_vault.authority = ctx.accounts.authority.key();
+    election_vault.total_reimbursed = 0;
+    election_vault.bump = ctx.bumps.election_vault;
+
+    msg!("Election vault created");
+    msg!("Election: {}", ctx.accounts.election.title);
+
+    Ok(())
+}
+
+// FUND ELECTION VAULT
+#[derive(Accounts)]
+pub struct FundElectionVault<'info> {
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_VAULT_SEED, election.key().as_ref()],
+        bump = election_vault.bump,
+        has_one = authority @ VotingError::Unauthorized
+    )]
+    pub election_vault: Account<'info, ElectionVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_election_vault(ctx: Context<FundElectionVault>, amount: u64) -> Result<()> {
+    require!(amount > 0, VotingError::InvalidInput);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.election_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    msg!("Election vault funded");
+    msg!("Amount: {}", amount);
+    msg!("Balance: {}", ctx.accounts.election_vault.to_account_info().lamports());
+
+    Ok(())
+}
+
+// WITHDRAW ELECTION VAULT (once the election is over)
+#[derive(Accounts)]
+pub struct WithdrawElectionVault<'info> {
+    #[account(
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = matches!(
+            election.status,
+            ElectionStatus::Finalized | ElectionStatus::Cancelled
+        ) @ VotingError::CannotModifyActiveElection
+    )]
+    pub election: Account<'info, Election>,
+
+    #[account(
+        mut,
+        seeds = [ELECTION_VAULT_SEED, election.key().as_ref()],
+        bump = election_vault.bump,
+        has_one = authority @ VotingError::Unauthorized
+    )]
+    pub election_vault: Account<'info, ElectionVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn withdraw_election_vault(ctx: Context<WithdrawElectionVault>) -> Result<()> {
+    let vault_info = ctx.accounts.election_vault.to_account_info();
+    let amount = available_lamports(&vault_info)?;
+
+    pay_from_vault(&vault_info, &ctx.accounts.authority.to_account_info(), amount)?;
+
+    msg!("Election vault withdrawn");
+    msg!("Amount: {}", amount);
+
+    Ok(())
+}
+
+// CLOSE ARCHIVED ELECTION VAULT (works after the election is closed)
+//
+// Pays the whole vault, rent included, to its authority. Vote records the
+// vault sponsored return their rent to the authority from then on.
+#[derive(Accounts)]
+pub struct CloseArchivedElectionVault<'info> {
+    #[account(
+        mut,
+        seeds = [ARCHIVED_ELECTION_SEED, election_vault.election.as_ref()],
+        bump = archived_election.bump
+    )]
+    pub archived_election: Box<Account<'info, ArchivedElection>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ELECTION_VAULT_SEED, archived_election.election.as_ref()],
+        bump = election_vault.bump,
+        has_one = authority @ VotingError::Unauthorized
+    )]
+    pub election_vault: Account<'info, ElectionVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn close_archived_election_vault(ctx: Context<CloseArchivedElectionVault>) -> Result<()> {
+    let amount = ctx.accounts.election_vault.to_account_info().lamports();
+
+    let archive = &mut ctx.accounts.archived_election;
+    archive.vault_closed_to = Some(ctx.accounts.authority.key());
+
+    msg!("Election vault closed");
+    msg!("Election ID: {}", archive.election_id);
+    msg!("Amount: {}", amount);
+
+    Ok(())
+}
+
+// Returns true when `vault_info` is the initialized vault of `election_key`.
+// Instructions take the vault as an optional account: anything else means
+// the payer covers the cost.
+pub(crate) fn is_election_vault(
+    vault_info: &AccountInfo,
+    election_key: &Pubkey,
+    program_id: &Pubkey,
+) -> bool {
+    let (expected_vault_pda, _) = Pubkey::find_program_address(
+        &[ELECTION_VAULT_SEED, election_key.as_ref()],
+        program_id
+    );
+
+    vault_info.key() == expected_vault_pda && vault_info.owner == program_id
+}
+
+// Lamports the vault can pay out while staying rent exempt
+pub(crate) fn available_lamports(vault_info: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(vault_info.data_len());
+    Ok(vault_info.lamports().saturating_sub(rent_exempt))
+}
+
+// Moves lamports out of the program-owned vault
+pub(crate) fn pay_from_vault(
+    vault_info: &AccountInfo,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount <= available_lamports(vault_info)?,
+        VotingError::InsufficientVaultFunds
+    );
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    Ok(())
+}
+
+// Refunds `records` freshly created accounts of `space` bytes to the payer
+// and records the payout on the vault
+pub(crate) fn reimburse_rent(
+    vault_info: &AccountInfo,
+    payer: &AccountInfo,
+    space: usize,
+    records: u64,
+) -> Result<u64> {
+    let amount = Rent::get()?
+        .minimum_balance(space)
+        .saturating_mul(records);
+    pay_from_vault(vault_info, payer, amount)?;
+
+    let mut election_vault = {
+        let data = vault_info.try_borrow_data()?;
+        ElectionVault::try_deserialize(&mut &data[..])?
+    };
+    election_vault.total_reimbursed = election_vault.total_reimbursed.saturating_add(amount);
+    write_account(&election_vault, vault_info)?;
+
+    Ok(amount)
+}
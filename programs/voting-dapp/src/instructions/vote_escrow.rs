@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+
+// CREATE VOTE ESCROW
+#[derive(Accounts)]
+pub struct CreateVoteEscrow<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = VoteEscrow::SIZE,
+        seeds = [VOTE_ESCROW_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        init,
+        payer = owner,
+        token::mint = mint,
+        token::authority = vote_escrow,
+        seeds = [ESCROW_VAULT_SEED, vote_escrow.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_vote_escrow(
+    ctx: Context<CreateVoteEscrow>,
+    amount: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+
+    require!(amount > 0, VotingError::InvalidInput);
+    require!(
+        (MIN_LOCK_DURATION..=MAX_LOCK_DURATION).contains(&lock_duration),
+        VotingError::InvalidLockDuration
+    );
+
+    // Move the tokens into the escrow vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let vote_escrow = &mut ctx.accounts.vote_escrow;
+    let clock = Clock::get()?;
+
+    vote_escrow.owner = ctx.accounts.owner.key();
+    vote_escrow.mint = ctx.accounts.mint.key();
+    vote_escrow.amount = amount;
+    vote_escrow.locked_at = clock.unix_timestamp;
+    vote_escrow.lock_end = clock.unix_timestamp.checked_add(lock_duration).unwrap();
+    vote_escrow.bump = ctx.bumps.vote_escrow;
+    vote_escrow.vault_bump = ctx.bumps.escrow_vault;
+
+    msg!("Vote escrow created");
+    msg!("Owner: {}", vote_escrow.owner);
+    msg!("Amount: {}", vote_escrow.amount);
+    msg!("Lock end: {}", vote_escrow.lock_end);
+
+    Ok(())
+}
+
+// WITHDRAW VOTE ESCROW (only once the lock has ended)
+#[derive(Accounts)]
+pub struct WithdrawVoteEscrow<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [VOTE_ESCROW_SEED, owner.key().as_ref()],
+        bump = vote_escrow.bump,
+        has_one = owner @ VotingError::Unauthorized
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED, vote_escrow.key().as_ref()],
+        bump = vote_escrow.vault_bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vote_escrow.mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_vote_escrow(ctx: Context<WithdrawVoteEscrow>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vote_escrow = &ctx.accounts.vote_escrow;
+
+    require!(
+        !vote_escrow.is_locked(clock.unix_timestamp),
+        VotingError::EscrowStillLocked
+    );
+
+    let owner_key = vote_escrow.owner;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VOTE_ESCROW_SEED,
+        owner_key.as_ref(),
+        &[vote_escrow.bump],
+    ]];
+
+    // Return the tokens and close the vault
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.vote_escrow.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.escrow_vault.amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.escrow_vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vote_escrow.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Vote escrow withdrawn");
+    msg!("Owner: {}", owner_key);
+    msg!("Amount: {}", ctx.accounts.vote_escrow.amount);
+
+    Ok(())
+}
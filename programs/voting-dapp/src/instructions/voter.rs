@@ -13,9 +13,11 @@ pub struct RequestVoterRegistration<'info> {
     pub admin_registry: Account<'info, AdminRegistry>,
     
     #[account(
+        mut, // MUTABLE to count the registration
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
         bump = election.bump,
-        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::InvalidInput
+        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::InvalidInput,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
     )]
     pub election: Account<'info, Election>,
     
@@ -40,6 +42,7 @@ pub struct RequestVoterRegistration<'info> {
 
 pub fn request_voter_registration(ctx: Context<RequestVoterRegistration>) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    check_registration_window(&ctx.accounts.election)?;
     
     let voter_registration = &mut ctx.accounts.voter_registration;
     let clock = Clock::get()?;
@@ -50,7 +53,13 @@ pub fn request_voter_registration(ctx: Context<RequestVoterRegistration>) -> Res
     voter_registration.requested_at = clock.unix_timestamp;
     voter_registration.approved_at = None;
     voter_registration.approved_by = None;
+    voter_registration.weight = 1;
+    voter_registration.district_id = None;
+    voter_registration.payer = ctx.accounts.voter.key();
     voter_registration.bump = ctx.bumps.voter_registration;
+
+    let election = &mut ctx.accounts.election;
+    election.registration_count = election.registration_count.saturating_add(1);
     
     msg!("Voter registration requested");
     msg!("Voter: {}", ctx.accounts.voter.key());
@@ -69,9 +78,11 @@ pub struct AddVoterDirectly<'info> {
     pub admin_registry: Account<'info, AdminRegistry>,
 
     #[account(
+        mut, // MUTABLE to count the registration
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
         bump = election.bump,
-        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::InvalidInput
+        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::InvalidInput,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
     )]
     pub election: Account<'info, Election>,
 
@@ -97,8 +108,18 @@ pub struct AddVoterDirectly<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn add_voter_directly(ctx: Context<AddVoterDirectly>) -> Result<()> {
+pub fn add_voter_directly(
+    ctx: Context<AddVoterDirectly>,
+    weight: u64,
+    district_id: Option<u16>,
+) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(weight > 0, VotingError::InvalidVoterWeight);
+    check_registration_window(&ctx.accounts.election)?;
+    require!(
+        ctx.accounts.election.is_valid_district(district_id),
+        VotingError::InvalidDistrict
+    );
 
     // Check if authority is either super admin or admin with can_manage_voters permission
     let is_super_admin = ctx.accounts.authority.key() == SUPER_ADMIN;
@@ -113,7 +134,7 @@ pub fn add_voter_directly(ctx: Context<AddVoterDirectly>) -> Result<()> {
         let (admin_pda, _) = Pubkey::find_program_address(admin_seeds, ctx.program_id);
 
         // Try to load and validate admin account
-        let admin_account_info = ctx.remaining_accounts.get(0)
+        let admin_account_info = ctx.remaining_accounts.first()
             .ok_or(VotingError::Unauthorized)?;
 
         require!(
@@ -140,10 +161,17 @@ pub fn add_voter_directly(ctx: Context<AddVoterDirectly>) -> Result<()> {
     voter_registration.requested_at = clock.unix_timestamp;
     voter_registration.approved_at = Some(clock.unix_timestamp);
     voter_registration.approved_by = Some(ctx.accounts.authority.key());
+    voter_registration.weight = weight;
+    voter_registration.district_id = district_id;
+    voter_registration.payer = ctx.accounts.authority.key();
     voter_registration.bump = ctx.bumps.voter_registration;
 
+    let election = &mut ctx.accounts.election;
+    election.registration_count = election.registration_count.saturating_add(1);
+
     msg!("Voter added directly by admin");
     msg!("Voter: {}", ctx.accounts.voter.key());
+    msg!("Weight: {}", weight);
     msg!("Added by: {}", ctx.accounts.authority.key());
     msg!("Election: {}", ctx.accounts.election.title);
 
@@ -169,7 +197,8 @@ pub struct ApproveVoterRegistration<'info> {
     
     #[account(
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
-        bump = election.bump
+        bump = election.bump,
+        constraint = !election.is_paused @ VotingError::ElectionPaused
     )]
     pub election: Account<'info, Election>,
     
@@ -188,8 +217,18 @@ pub struct ApproveVoterRegistration<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn approve_voter_registration(ctx: Context<ApproveVoterRegistration>) -> Result<()> {
+pub fn approve_voter_registration(
+    ctx: Context<ApproveVoterRegistration>,
+    weight: u64,
+    district_id: Option<u16>,
+) -> Result<()> {
     require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    require!(weight > 0, VotingError::InvalidVoterWeight);
+    check_registration_window(&ctx.accounts.election)?;
+    require!(
+        ctx.accounts.election.is_valid_district(district_id),
+        VotingError::InvalidDistrict
+    );
     
     let voter_registration = &mut ctx.accounts.voter_registration;
     let clock = Clock::get()?;
@@ -197,9 +236,12 @@ pub fn approve_voter_registration(ctx: Context<ApproveVoterRegistration>) -> Res
     voter_registration.status = RegistrationStatus::Approved;
     voter_registration.approved_at = Some(clock.unix_timestamp);
     voter_registration.approved_by = Some(ctx.accounts.authority.key());
+    voter_registration.weight = weight;
+    voter_registration.district_id = district_id;
     
     msg!("Voter registration approved");
     msg!("Voter: {}", voter_registration.voter);
+    msg!("Weight: {}", weight);
     msg!("Approved by: {}", ctx.accounts.authority.key());
     
     Ok(())
@@ -224,7 +266,9 @@ pub struct RejectVoterRegistration<'info> {
     
     #[account(
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
-        bump = election.bump
+        bump = election.bump,
+        constraint = !election.is_paused @ VotingError::ElectionPaused,
+        constraint = !election.voter_roll_frozen @ VotingError::VoterRollFrozen
     )]
     pub election: Account<'info, Election>,
     
@@ -275,7 +319,9 @@ pub struct RevokeVoterRegistration<'info> {
     
     #[account(
         seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
-        bump = election.bump
+        bump = election.bump,
+        constraint = !election.is_paused @ VotingError::ElectionPaused,
+        constraint = !election.voter_roll_frozen @ VotingError::VoterRollFrozen
     )]
     pub election: Account<'info, Election>,
     
@@ -304,5 +350,65 @@ pub fn revoke_voter_registration(ctx: Context<RevokeVoterRegistration>) -> Resul
     msg!("Voter: {}", voter_registration.voter);
     msg!("Revoked by: {}", ctx.accounts.authority.key());
     
+    Ok(())
+}
+
+// FREEZE VOTER ROLL (no registrations accepted afterwards)
+#[derive(Accounts)]
+pub struct FreezeVoterRoll<'info> {
+    #[account(
+        seeds = [ADMIN_REGISTRY_SEED],
+        bump = admin_registry.bump
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ VotingError::AdminNotActive,
+        constraint = admin_account.permissions.can_manage_voters @ VotingError::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, Admin>,
+    
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, election.election_id.to_le_bytes().as_ref()],
+        bump = election.bump,
+        constraint = election.voter_registration_type == VoterRegistrationType::Whitelist @ VotingError::InvalidInput,
+        constraint = election.can_be_modified() @ VotingError::CannotModifyActiveElection,
+        constraint = !election.voter_roll_frozen @ VotingError::VoterRollFrozen
+    )]
+    pub election: Account<'info, Election>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn freeze_voter_roll(ctx: Context<FreezeVoterRoll>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.paused, VotingError::SystemPaused);
+    
+    let election = &mut ctx.accounts.election;
+    election.voter_roll_frozen = true;
+    
+    msg!("Voter roll frozen");
+    msg!("Election: {}", election.title);
+    msg!("Frozen by: {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
+
+// Rejects registration changes outside the election's registration window
+fn check_registration_window(election: &Election) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(!election.voter_roll_frozen, VotingError::VoterRollFrozen);
+    require!(
+        !election.is_registration_closed(clock.unix_timestamp),
+        VotingError::RegistrationClosed
+    );
+    require!(
+        clock.unix_timestamp >= election.registration_start,
+        VotingError::RegistrationNotOpen
+    );
+    
     Ok(())
 }
\ No newline at end of file
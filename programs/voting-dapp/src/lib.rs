@@ -2,8 +2,13 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
+
+#[cfg(test)]
+mod test_harness;
 
 use instructions::*;
 
@@ -57,6 +62,7 @@ pub mod voting_dapp {
         start_time: i64,
         end_time: i64,
         voter_registration_type: state::VoterRegistrationType,
+        vote_weighting: state::VoteWeighting,
     ) -> Result<()> {
         instructions::election::create_election(
             ctx,
@@ -65,9 +71,26 @@ pub mod voting_dapp {
             start_time,
             end_time,
             voter_registration_type,
+            vote_weighting,
         )
     }
 
+    pub fn clone_election<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloneElection<'info>>,
+        start_time: i64,
+        end_time: i64,
+        candidate_count: u32,
+    ) -> Result<()> {
+        instructions::election::clone_election(ctx, start_time, end_time, candidate_count)
+    }
+
+    pub fn update_election(
+        ctx: Context<UpdateElection>,
+        params: UpdateElectionParams,
+    ) -> Result<()> {
+        instructions::election::update_election(ctx, params)
+    }
+
     pub fn start_election(ctx: Context<StartElection>) -> Result<()> {
         instructions::election::start_election(ctx)
     }
@@ -80,35 +103,267 @@ pub mod voting_dapp {
         instructions::election::cancel_election(ctx)
     }
 
-    pub fn finalize_election(ctx: Context<FinalizeElection>) -> Result<()> {
+    pub fn delete_election<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeleteElection<'info>>,
+    ) -> Result<()> {
+        instructions::election::delete_election(ctx)
+    }
+
+    pub fn finalize_election<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeElection<'info>>,
+    ) -> Result<()> {
         instructions::election::finalize_election(ctx)
     }
 
+    pub fn extend_election(
+        ctx: Context<ExtendElection>,
+        new_end_time: i64,
+        reason: state::ExtensionReason,
+    ) -> Result<()> {
+        instructions::election::extend_election(ctx, new_end_time, reason)
+    }
+
+    pub fn pause_election(ctx: Context<PauseElection>) -> Result<()> {
+        instructions::election::pause_election(ctx)
+    }
+
+    pub fn resume_election(ctx: Context<ResumeElection>, extend_end_time: bool) -> Result<()> {
+        instructions::election::resume_election(ctx, extend_end_time)
+    }
+
+    pub fn configure_lifecycle_crank(
+        ctx: Context<ConfigureLifecycleCrank>,
+        auto_start: bool,
+        crank_bounty: u64,
+    ) -> Result<()> {
+        instructions::election::configure_lifecycle_crank(ctx, auto_start, crank_bounty)
+    }
+
+    pub fn crank_election(ctx: Context<CrankElection>) -> Result<()> {
+        instructions::election::crank_election(ctx)
+    }
+
+    // DISTRICTS
+    pub fn create_district(ctx: Context<CreateDistrict>, name: String) -> Result<()> {
+        instructions::district::create_district(ctx, name)
+    }
+
+    // CONTESTS
+    pub fn create_contest(
+        ctx: Context<CreateContest>,
+        title: String,
+        seats: u8,
+        ballot_type: state::BallotType,
+    ) -> Result<()> {
+        instructions::contest::create_contest(ctx, title, seats, ballot_type)
+    }
+
+    // PARTIES
+    pub fn configure_party_list(
+        ctx: Context<ConfigurePartyList>,
+        seats: u16,
+        seat_allocation: state::SeatAllocationMethod,
+    ) -> Result<()> {
+        instructions::party::configure_party_list(ctx, seats, seat_allocation)
+    }
+
+    pub fn create_party(ctx: Context<CreateParty>, name: String) -> Result<()> {
+        instructions::party::create_party(ctx, name)
+    }
+
+    pub fn add_party_candidate(ctx: Context<AddPartyCandidate>) -> Result<()> {
+        instructions::party::add_party_candidate(ctx)
+    }
+
+    pub fn assign_party_seats<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AssignPartySeats<'info>>,
+    ) -> Result<()> {
+        instructions::party::assign_party_seats(ctx)
+    }
+
+    // SERIES
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: u64,
+        name: String,
+        description: String,
+        voting_duration: i64,
+        voter_registration_type: state::VoterRegistrationType,
+        vote_weighting: state::VoteWeighting,
+    ) -> Result<()> {
+        instructions::series::create_series(
+            ctx,
+            series_id,
+            name,
+            description,
+            voting_duration,
+            voter_registration_type,
+            vote_weighting,
+        )
+    }
+
+    pub fn create_series_election(
+        ctx: Context<CreateSeriesElection>,
+        start_time: i64,
+    ) -> Result<()> {
+        instructions::series::create_series_election(ctx, start_time)
+    }
+
+    pub fn record_edition_results<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordEditionResults<'info>>,
+    ) -> Result<()> {
+        instructions::series::record_edition_results(ctx)
+    }
+
+    // PRIMARIES
+    pub fn link_primary(
+        ctx: Context<LinkPrimary>,
+        promotion_rule: state::PromotionRule,
+    ) -> Result<()> {
+        instructions::primary::link_primary(ctx, promotion_rule)
+    }
+
+    pub fn unlink_primary(ctx: Context<UnlinkPrimary>) -> Result<()> {
+        instructions::primary::unlink_primary(ctx)
+    }
+
+    pub fn promote_primary_candidates<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PromotePrimaryCandidates<'info>>,
+    ) -> Result<()> {
+        instructions::primary::promote_primary_candidates(ctx)
+    }
+
     // CANDIDATE MANAGEMENT
     pub fn add_candidate(
         ctx: Context<AddCandidate>,
         name: String,
         description: String,
         image_url: String,
+        district_id: Option<u16>,
+        contest_id: Option<u16>,
+        wallet: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::candidate::add_candidate(
+            ctx,
+            name,
+            description,
+            image_url,
+            district_id,
+            contest_id,
+            wallet,
+        )
+    }
+
+    pub fn update_candidate(
+        ctx: Context<UpdateCandidate>,
+        name: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+    ) -> Result<()> {
+        instructions::candidate::update_candidate(ctx, name, description, image_url)
+    }
+
+    pub fn set_candidate_metadata(
+        ctx: Context<UpdateCandidate>,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::candidate::add_candidate(ctx, name, description, image_url)
+        instructions::candidate::set_candidate_metadata(ctx, metadata_uri, metadata_hash)
     }
 
     pub fn remove_candidate(ctx: Context<RemoveCandidate>) -> Result<()> {
         instructions::candidate::remove_candidate(ctx)
     }
 
+    pub fn withdraw_candidate(ctx: Context<WithdrawCandidate>) -> Result<()> {
+        instructions::candidate::withdraw_candidate(ctx)
+    }
+
+    pub fn disqualify_candidate(ctx: Context<DisqualifyCandidate>) -> Result<()> {
+        instructions::candidate::disqualify_candidate(ctx)
+    }
+
+    // NOMINATIONS
+    pub fn submit_nomination(
+        ctx: Context<SubmitNomination>,
+        name: String,
+        description: String,
+        image_url: String,
+        district_id: Option<u16>,
+        contest_id: Option<u16>,
+    ) -> Result<()> {
+        instructions::nomination::submit_nomination(
+            ctx,
+            name,
+            description,
+            image_url,
+            district_id,
+            contest_id,
+        )
+    }
+
+    pub fn approve_nomination(ctx: Context<ApproveNomination>) -> Result<()> {
+        instructions::nomination::approve_nomination(ctx)
+    }
+
+    pub fn reject_nomination(ctx: Context<RejectNomination>) -> Result<()> {
+        instructions::nomination::reject_nomination(ctx)
+    }
+
+    pub fn close_nomination(ctx: Context<CloseNomination>) -> Result<()> {
+        instructions::nomination::close_nomination(ctx)
+    }
+
+    // CANDIDATE DEPOSITS
+    pub fn configure_candidate_deposit(
+        ctx: Context<ConfigureCandidateDeposit>,
+        amount: u64,
+        mint: Option<Pubkey>,
+        refund_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::deposit::configure_candidate_deposit(ctx, amount, mint, refund_bps, treasury)
+    }
+
+    pub fn pay_candidate_deposit(ctx: Context<PayCandidateDeposit>) -> Result<()> {
+        instructions::deposit::pay_candidate_deposit(ctx)
+    }
+
+    pub fn pay_candidate_deposit_token(ctx: Context<PayCandidateDepositToken>) -> Result<()> {
+        instructions::deposit::pay_candidate_deposit_token(ctx)
+    }
+
+    pub fn settle_candidate_deposit(ctx: Context<SettleCandidateDeposit>) -> Result<()> {
+        instructions::deposit::settle_candidate_deposit(ctx)
+    }
+
+    pub fn settle_candidate_deposit_token(ctx: Context<SettleCandidateDepositToken>) -> Result<()> {
+        instructions::deposit::settle_candidate_deposit_token(ctx)
+    }
+
     // VOTER REGISTRATION
     pub fn request_voter_registration(ctx: Context<RequestVoterRegistration>) -> Result<()> {
         instructions::voter::request_voter_registration(ctx)
     }
 
-    pub fn add_voter_directly(ctx: Context<AddVoterDirectly>) -> Result<()> {
-        instructions::voter::add_voter_directly(ctx)
+    pub fn add_voter_directly(
+        ctx: Context<AddVoterDirectly>,
+        weight: u64,
+        district_id: Option<u16>,
+    ) -> Result<()> {
+        instructions::voter::add_voter_directly(ctx, weight, district_id)
+    }
+
+    pub fn approve_voter_registration(
+        ctx: Context<ApproveVoterRegistration>,
+        weight: u64,
+        district_id: Option<u16>,
+    ) -> Result<()> {
+        instructions::voter::approve_voter_registration(ctx, weight, district_id)
     }
 
-    pub fn approve_voter_registration(ctx: Context<ApproveVoterRegistration>) -> Result<()> {
-        instructions::voter::approve_voter_registration(ctx)
+    pub fn freeze_voter_roll(ctx: Context<FreezeVoterRoll>) -> Result<()> {
+        instructions::voter::freeze_voter_roll(ctx)
     }
 
     pub fn reject_voter_registration(ctx: Context<RejectVoterRegistration>) -> Result<()> {
@@ -119,8 +374,136 @@ pub mod voting_dapp {
         instructions::voter::revoke_voter_registration(ctx)
     }
 
+    // ELECTION VAULT
+    pub fn create_election_vault(ctx: Context<CreateElectionVault>) -> Result<()> {
+        instructions::vault::create_election_vault(ctx)
+    }
+
+    pub fn fund_election_vault(ctx: Context<FundElectionVault>, amount: u64) -> Result<()> {
+        instructions::vault::fund_election_vault(ctx, amount)
+    }
+
+    pub fn withdraw_election_vault(ctx: Context<WithdrawElectionVault>) -> Result<()> {
+        instructions::vault::withdraw_election_vault(ctx)
+    }
+
+    pub fn close_archived_election_vault(
+        ctx: Context<CloseArchivedElectionVault>,
+    ) -> Result<()> {
+        instructions::vault::close_archived_election_vault(ctx)
+    }
+
+    // VOTE ESCROW
+    pub fn create_vote_escrow(
+        ctx: Context<CreateVoteEscrow>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::vote_escrow::create_vote_escrow(ctx, amount, lock_duration)
+    }
+
+    pub fn withdraw_vote_escrow(ctx: Context<WithdrawVoteEscrow>) -> Result<()> {
+        instructions::vote_escrow::withdraw_vote_escrow(ctx)
+    }
+
+    // DELEGATION
+    pub fn create_delegation(ctx: Context<CreateDelegation>) -> Result<()> {
+        instructions::delegation::create_delegation(ctx)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::delegation::revoke_delegation(ctx)
+    }
+
+    pub fn apply_delegated_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyDelegatedVotes<'info>>,
+    ) -> Result<()> {
+        instructions::delegation::apply_delegated_votes(ctx)
+    }
+
+    pub fn override_delegated_vote(ctx: Context<OverrideDelegatedVote>) -> Result<()> {
+        instructions::delegation::override_delegated_vote(ctx)
+    }
+
     // VOTING
-    pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
+    pub fn cast_vote<'info>(ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>) -> Result<()> {
         instructions::cast_vote::cast_vote(ctx)
     }
+
+    pub fn cast_ballot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastBallot<'info>>,
+        choice_counts: Vec<u8>,
+    ) -> Result<()> {
+        instructions::contest::cast_ballot(ctx, choice_counts)
+    }
+
+    pub fn cast_party_vote(ctx: Context<CastPartyVote>) -> Result<()> {
+        instructions::party::cast_party_vote(ctx)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>) -> Result<()> {
+        instructions::cast_vote::change_vote(ctx)
+    }
+
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        instructions::cast_vote::retract_vote(ctx)
+    }
+
+    // ARCHIVAL
+    pub fn archive_election(ctx: Context<ArchiveElection>) -> Result<()> {
+        instructions::archive::archive_election(ctx)
+    }
+
+    pub fn close_vote_records<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseVoteRecords<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_vote_records(ctx)
+    }
+
+    pub fn close_voter_registrations<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseVoterRegistrations<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_voter_registrations(ctx)
+    }
+
+    pub fn close_candidates<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseCandidates<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_candidates(ctx)
+    }
+
+    pub fn close_contest_vote_records<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseContestVoteRecords<'info>>,
+        contest_id: u16,
+    ) -> Result<()> {
+        instructions::archive::close_contest_vote_records(ctx, contest_id)
+    }
+
+    pub fn close_contests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseContests<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_contests(ctx)
+    }
+
+    pub fn close_districts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseDistricts<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_districts(ctx)
+    }
+
+    pub fn close_party_vote_records<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClosePartyVoteRecords<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_party_vote_records(ctx)
+    }
+
+    pub fn close_parties<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseParties<'info>>,
+    ) -> Result<()> {
+        instructions::archive::close_parties(ctx)
+    }
+
+    pub fn close_archived_election(ctx: Context<CloseArchivedElection>) -> Result<()> {
+        instructions::archive::close_archived_election(ctx)
+    }
 }
\ No newline at end of file
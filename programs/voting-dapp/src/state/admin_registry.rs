@@ -4,6 +4,7 @@ use anchor_lang::prelude::*;
 pub struct AdminRegistry {
     pub super_admin: Pubkey,
     pub election_count: u64,      
+    pub deleted_election_count: u64, 
     pub admin_count: u32,      
     pub paused: bool,             
     pub bump: u8,                 
@@ -14,6 +15,7 @@ impl AdminRegistry {
         32 + // super_admin
         4 +  // admin_count
         8 +  // election_count
+        8 +  // deleted_election_count
         1 +  // paused
         1;   // bump
 }
\ No newline at end of file
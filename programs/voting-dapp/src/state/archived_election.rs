@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use super::ElectionStatus;
+use crate::constants::VOTE_RECORD_TREE_DEPTH;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CandidateTally {
+    pub candidate_id: u32,        // Candidate ID within the election
+    pub contest_id: Option<u16>,  // Contest (None = main race)
+    pub vote_count: u64,          // Final vote count
+}
+
+impl CandidateTally {
+    pub const SIZE: usize = 4 + // candidate_id
+        1 + 2 + // contest_id (Option<u16>)
+        8;      // vote_count
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PartyResult {
+    pub party_id: u16,            // Party ID within the election
+    pub vote_count: u64,          // Final party-list vote weight
+    pub seats_won: u16,           // Seats allocated at finalization
+    pub seats_filled: u16,        // Seats assigned to list candidates
+}
+
+impl PartyResult {
+    pub const SIZE: usize = 2 + // party_id
+        8 + // vote_count
+        2 + // seats_won
+        2;  // seats_filled
+}
+
+// Summary kept after a finished election's accounts are closed.
+// Closed vote records are appended to an incremental Merkle tree, so a
+// voter can later prove their vote against `vote_records_root`.
+#[account]
+pub struct ArchivedElection {
+    pub election: Pubkey,                            // Archived election
+    pub election_id: u64,                            // Election ID
+    pub title: String,                               // Election title
+    pub status: ElectionStatus,                      // Finalized or Cancelled
+    pub total_votes: u64,                            // Final vote weight
+    pub total_ballots: u64,                          // Final turnout
+    pub candidate_count: u32,                        // Candidates at archival
+    pub closed_at: i64,                              // When finalized or cancelled
+    pub archived_at: i64,                            // Archive timestamp
+    pub election_closed: bool,                       // Election account closed
+    pub candidate_tallies: Vec<CandidateTally>,      // Tallies of closed candidates
+    pub vote_record_count: u64,                      // Vote records closed so far
+    pub vote_records_root: [u8; 32],                 // Merkle root of closed vote records
+    pub vote_record_frontier: [[u8; 32]; VOTE_RECORD_TREE_DEPTH], // Merkle tree frontier
+    pub vault_closed_to: Option<Pubkey>,             // Vault authority, once the vault is closed
+    pub party_votes: u64,                            // Final party-list vote weight
+    pub party_count: u16,                            // Parties at archival
+    pub party_results: Vec<PartyResult>,             // Results of closed parties
+    pub bump: u8,                                    // PDA bump
+}
+
+impl ArchivedElection {
+    pub fn size(candidate_count: u32, party_count: u16) -> usize {
+        8 +  // discriminator
+        32 + // election
+        8 +  // election_id
+        4 + 100 + // title (String with max 100 chars)
+        1 +  // status
+        8 +  // total_votes
+        8 +  // total_ballots
+        4 +  // candidate_count
+        8 +  // closed_at
+        8 +  // archived_at
+        1 +  // election_closed
+        4 + candidate_count as usize * CandidateTally::SIZE + // candidate_tallies
+        8 +  // vote_record_count
+        32 + // vote_records_root
+        32 * VOTE_RECORD_TREE_DEPTH + // vote_record_frontier
+        1 + 32 + // vault_closed_to (Option<Pubkey>)
+        8 +  // party_votes
+        2 +  // party_count
+        4 + party_count as usize * PartyResult::SIZE + // party_results
+        1    // bump
+    }
+
+    // Where the rent of a record paid by `payer` goes. Records the election
+    // vault sponsored follow the vault's authority once the vault is closed.
+    pub fn rent_recipient(&self, payer: Pubkey, election_vault: &Pubkey) -> Pubkey {
+        match self.vault_closed_to {
+            Some(recipient) if payer == *election_vault => recipient,
+            _ => payer,
+        }
+    }
+
+    pub fn all_candidates_closed(&self) -> bool {
+        self.candidate_tallies.len() == self.candidate_count as usize
+    }
+
+    pub fn all_parties_closed(&self) -> bool {
+        self.party_results.len() == self.party_count as usize
+    }
+
+    // Appends a leaf to the incremental Merkle tree
+    pub fn append_vote_record(&mut self, leaf: [u8; 32]) -> bool {
+        if self.vote_record_count >= 1u64 << VOTE_RECORD_TREE_DEPTH {
+            return false;
+        }
+
+        let mut node = leaf;
+        let mut size = self.vote_record_count + 1;
+        for height in 0..VOTE_RECORD_TREE_DEPTH {
+            if size & 1 == 1 {
+                self.vote_record_frontier[height] = node;
+                break;
+            }
+            node = hashv(&[&self.vote_record_frontier[height], &node]).to_bytes();
+            size >>= 1;
+        }
+
+        self.vote_record_count += 1;
+        true
+    }
+
+    // Recomputes the root from the frontier; empty leaves are zero
+    pub fn update_vote_records_root(&mut self) {
+        let mut node = [0u8; 32];
+        let mut zero = [0u8; 32];
+        let mut size = self.vote_record_count;
+        for height in 0..VOTE_RECORD_TREE_DEPTH {
+            node = if size & 1 == 1 {
+                hashv(&[&self.vote_record_frontier[height], &node]).to_bytes()
+            } else {
+                hashv(&[&node, &zero]).to_bytes()
+            };
+            zero = hashv(&[&zero, &zero]).to_bytes();
+            size >>= 1;
+        }
+        self.vote_records_root = node;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive() -> ArchivedElection {
+        ArchivedElection {
+            election: Pubkey::default(),
+            election_id: 0,
+            title: String::new(),
+            status: ElectionStatus::Finalized,
+            total_votes: 0,
+            total_ballots: 0,
+            candidate_count: 0,
+            closed_at: 0,
+            archived_at: 0,
+            election_closed: false,
+            candidate_tallies: vec![],
+            vote_record_count: 0,
+            vote_records_root: [0u8; 32],
+            vote_record_frontier: [[0u8; 32]; VOTE_RECORD_TREE_DEPTH],
+            vault_closed_to: None,
+            party_votes: 0,
+            party_count: 0,
+            party_results: vec![],
+            bump: 0,
+        }
+    }
+
+    fn leaf(index: u64) -> [u8; 32] {
+        hashv(&[&index.to_le_bytes()]).to_bytes()
+    }
+
+    // Root of the full depth-VOTE_RECORD_TREE_DEPTH tree, computed level by
+    // level with empty positions filled by the zero subtree of that height
+    fn reference_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+        let mut level = leaves.to_vec();
+        let mut zero = [0u8; 32];
+        for _ in 0..VOTE_RECORD_TREE_DEPTH {
+            if level.len() % 2 == 1 {
+                level.push(zero);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes())
+                .collect();
+            if level.is_empty() {
+                level.push(hashv(&[&zero, &zero]).to_bytes());
+            }
+            zero = hashv(&[&zero, &zero]).to_bytes();
+        }
+        level[0]
+    }
+
+    fn root_after(count: u64) -> ([u8; 32], [u8; 32]) {
+        let mut archive = archive();
+        let leaves: Vec<[u8; 32]> = (0..count).map(leaf).collect();
+        for leaf in leaves.iter() {
+            assert!(archive.append_vote_record(*leaf));
+        }
+        archive.update_vote_records_root();
+        (archive.vote_records_root, reference_root(&leaves))
+    }
+
+    #[test]
+    fn empty_tree_root_is_zero_subtree() {
+        let (root, expected) = root_after(0);
+        assert_eq!(root, expected);
+    }
+
+    #[test]
+    fn root_matches_reference_for_small_trees() {
+        for count in [1, 2, 3, 5, 6, 7] {
+            let (root, expected) = root_after(count);
+            assert_eq!(root, expected, "{} leaves", count);
+        }
+    }
+
+    #[test]
+    fn root_matches_reference_for_powers_of_two() {
+        for k in 0..=6 {
+            let (root, expected) = root_after(1 << k);
+            assert_eq!(root, expected, "{} leaves", 1u64 << k);
+        }
+    }
+
+    #[test]
+    fn root_changes_with_every_leaf() {
+        let (one, _) = root_after(1);
+        let (two, _) = root_after(2);
+        assert_ne!(one, two);
+    }
+
+    #[test]
+    fn sponsored_rent_follows_the_vault_authority_once_closed() {
+        let vault = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let mut archive = archive();
+
+        assert_eq!(archive.rent_recipient(vault, &vault), vault);
+        assert_eq!(archive.rent_recipient(voter, &vault), voter);
+
+        archive.vault_closed_to = Some(authority);
+        assert_eq!(archive.rent_recipient(vault, &vault), authority);
+        assert_eq!(archive.rent_recipient(voter, &vault), voter);
+    }
+
+    #[test]
+    fn full_tree_rejects_more_leaves() {
+        let mut archive = archive();
+        archive.vote_record_count = 1u64 << VOTE_RECORD_TREE_DEPTH;
+        assert!(!archive.append_vote_record(leaf(0)));
+    }
+}
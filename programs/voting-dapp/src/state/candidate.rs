@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use super::CandidateStatus;
 
 #[account]
 pub struct Candidate {
@@ -7,9 +8,24 @@ pub struct Candidate {
     pub name: String,             
     pub description: String,      
     pub image_url: String,        
+    pub metadata_uri: Option<String>, 
+    pub metadata_hash: Option<[u8; 32]>, 
+    pub district_id: Option<u16>, 
+    pub contest_id: Option<u16>,  
+    pub party_id: Option<u16>,    
+    pub list_position: u16,       
+    pub source_candidate: Option<Pubkey>, 
+    pub wallet: Option<Pubkey>,   
     pub vote_count: u64,          
+    pub elected: bool,            
+    pub deposit_paid: bool,       
+    pub status: CandidateStatus,  
+    pub status_changed_at: Option<i64>, 
+    pub voided_votes: u64,        
     pub added_by: Pubkey,         
     pub added_at: i64,            
+    pub last_updated_at: i64,     
+    pub payer: Pubkey,            
     pub bump: u8,                 
 }
 
@@ -18,10 +34,29 @@ impl Candidate {
         32 + // election
         4 +  // candidate_id
         4 + 50 + // name (String with max 50 chars)
-        4 + 500 + // description (String with max 500 chars)
+        4 + 200 + // description (String with max 200 chars)
         4 + 200 + // image_url (String with max 200 chars, default empty)
+        1 + 4 + 200 + // metadata_uri (Option<String> with max 200 chars)
+        1 + 32 + // metadata_hash (Option<[u8; 32]>, sha256 of the metadata document)
+        1 + 2 +  // district_id (Option<u16>)
+        1 + 2 +  // contest_id (Option<u16>)
+        1 + 2 +  // party_id (Option<u16>)
+        2 +  // list_position
+        1 + 32 + // source_candidate (Option<Pubkey>)
+        1 + 32 + // wallet (Option<Pubkey>)
         8 +  // vote_count
+        1 +  // elected
+        1 +  // deposit_paid
+        1 +  // status
+        1 + 8 + // status_changed_at (Option<i64>)
+        8 +  // voided_votes
         32 + // added_by
         8 +  // added_at
+        8 +  // last_updated_at
+        32 + // payer
         1;   // bump
+
+    pub fn is_active(&self) -> bool {
+        self.status == CandidateStatus::Active
+    }
 }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// Deposit escrowed for a candidate. SOL deposits are held as extra lamports
+// on this account; token deposits sit in a vault token account it owns.
+#[account]
+pub struct CandidateDeposit {
+    pub election: Pubkey,         // Election
+    pub candidate: Pubkey,        // Candidate the deposit is for
+    pub depositor: Pubkey,        // Wallet that paid and gets refunds
+    pub mint: Option<Pubkey>,     // Token mint (None = SOL)
+    pub amount: u64,              // Deposit held
+    pub paid_at: i64,             // Payment timestamp
+    pub bump: u8,                 // PDA bump
+    pub vault_bump: u8,           // Deposit vault PDA bump (token deposits)
+}
+
+impl CandidateDeposit {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        32 + // candidate
+        32 + // depositor
+        1 + 32 + // mint (Option<Pubkey>)
+        8 +  // amount
+        8 +  // paid_at
+        1 +  // bump
+        1;   // vault_bump
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use super::BallotType;
+use crate::constants::MAX_CONTEST_CHOICES;
+
+#[account]
+pub struct Contest {
+    pub election: Pubkey,          // Election
+    pub contest_id: u16,           // Index within the election
+    pub title: String,             // Contest title (e.g. "Treasurer")
+    pub seats: u8,                 // Seats to fill
+    pub ballot_type: BallotType,   // How many candidates a voter may pick
+    pub total_votes: u64,          // Vote weight cast across all choices
+    pub total_ballots: u64,        // Voters who voted in this contest
+    pub payer: Pubkey,             // Rent payer
+    pub bump: u8,                  // PDA bump
+}
+
+impl Contest {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        2 +  // contest_id
+        4 + 100 + // title (String with max 100 chars)
+        1 +  // seats
+        1 +  // ballot_type
+        8 +  // total_votes
+        8 +  // total_ballots
+        32 + // payer
+        1;   // bump
+
+    pub fn is_valid_choice_count(&self, choices: usize) -> bool {
+        let max_choices = match self.ballot_type {
+            BallotType::SingleChoice => 1,
+            BallotType::MultiChoice => self.seats,
+            BallotType::Approval => MAX_CONTEST_CHOICES,
+        };
+        choices >= 1 && choices <= max_choices as usize
+    }
+}
+
+#[account]
+pub struct ContestVoteRecord {
+    pub contest: Pubkey,           // Contest
+    pub voter: Pubkey,             // Voter wallet
+    pub candidates: Vec<Pubkey>,   // Candidates chosen
+    pub weight: u64,               // Voting power applied to each choice
+    pub payer: Pubkey,             // Rent payer (fee payer or election vault)
+    pub voted_at: i64,             // Vote timestamp
+    pub bump: u8,                  // PDA bump
+}
+
+impl ContestVoteRecord {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // contest
+        32 + // voter
+        4 + 32 * MAX_CONTEST_CHOICES as usize + // candidates (Vec<Pubkey>)
+        8 +  // weight
+        32 + // payer
+        8 +  // voted_at
+        1;   // bump
+}
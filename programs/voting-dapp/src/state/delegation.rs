@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Delegation {
+    pub delegator: Pubkey,         // Wallet handing over its voting power
+    pub delegate: Pubkey,          // Wallet voting on its behalf
+    pub election: Option<Pubkey>,  // Election scope (None = all elections)
+    pub created_at: i64,           // Delegation timestamp
+    pub bump: u8,                  // PDA bump
+}
+
+impl Delegation {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // delegator
+        32 + // delegate
+        1 + 32 + // election (Option<Pubkey>)
+        8 +  // created_at
+        1;   // bump
+
+    // Seed component for the delegation scope. Global delegations use the
+    // default pubkey, so a per-election delegation can coexist with them.
+    pub fn scope(election: Option<Pubkey>) -> Pubkey {
+        election.unwrap_or_default()
+    }
+}
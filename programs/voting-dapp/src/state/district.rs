@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct District {
+    pub election: Pubkey,      // Election
+    pub district_id: u16,      // Index within the election
+    pub name: String,          // District name
+    pub total_votes: u64,      // Vote weight cast in this district
+    pub total_ballots: u64,    // Voters who voted in this district
+    pub payer: Pubkey,         // Rent payer
+    pub bump: u8,              // PDA bump
+}
+
+impl District {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        2 +  // district_id
+        4 + 50 + // name (String with max 50 chars)
+        8 +  // total_votes
+        8 +  // total_ballots
+        32 + // payer
+        1;   // bump
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use super::{ElectionStatus, VoterRegistrationType};
+use solana_sha256_hasher::hashv;
+use super::{Candidate, ElectionStatus, ExtensionReason, PromotionRule, SeatAllocationMethod, VoteWeighting, VoterRegistrationType, WithdrawalPolicy};
 
 #[account]
 pub struct Election {
@@ -9,10 +10,53 @@ pub struct Election {
     pub description: String,                         // Election description
     pub start_time: i64,                             // Start timestamp
     pub end_time: i64,                               // End timestamp
+    pub registration_start: i64,                     // Voter registration opens
+    pub registration_end: i64,                       // Voter registration closes
+    pub voter_roll_frozen: bool,                     // No more registrations accepted
     pub status: ElectionStatus,                      // Current status
-    pub total_votes: u64,                            // Total votes cast
-    pub candidate_count: u32,                        // Number of candidates
+    pub total_votes: u64,                            // Total vote weight cast
+    pub total_ballots: u64,                          // Voters who voted (turnout)
+    pub candidate_count: u32,                        // Number of live candidates
+    pub next_candidate_id: u32,                      // ID (PDA seed) of the next candidate
+    pub max_candidates: u32,                         // Cap on live candidates
+    pub withdrawal_policy: WithdrawalPolicy,         // Fate of votes for withdrawn candidates
+    pub district_count: u16,                         // Number of districts
+    pub contest_count: u16,                          // Number of contests
+    pub party_count: u16,                            // Number of parties
+    pub registration_count: u32,                     // Voter registration accounts
+    pub nomination_count: u32,                       // Nomination accounts not yet closed
+    pub party_list_seats: u16,                       // Seats filled from party lists (0 = no party-list ballot)
+    pub seat_allocation: SeatAllocationMethod,       // Highest-averages method for party seats
+    pub party_votes: u64,                            // Vote weight cast on party-list ballots
+    pub party_ballots: u64,                          // Voters who cast a party-list ballot
+    pub seats_allocated: bool,                       // Party seats allocated at finalization
     pub voter_registration_type: VoterRegistrationType, // Registration type
+    pub vote_weighting: VoteWeighting,               // How voting power is computed
+    pub auto_start: bool,                            // Crank may start at start_time
+    pub crank_bounty: u64,                           // Lamports paid per crank from the vault
+    pub extension_count: u16,                        // Number of end time extensions
+    pub total_extension: i64,                        // Seconds added to end_time so far
+    pub max_total_extension: i64,                    // Cap on total_extension
+    pub last_extension_reason: Option<ExtensionReason>, // Reason for the latest extension
+    pub is_paused: bool,                             // Voting and registration halted
+    pub paused_at: Option<i64>,                      // When the current pause began
+    pub cloned_from: Option<Pubkey>,                 // Source election of a clone
+    pub series: Option<Pubkey>,                      // Series this election is an edition of
+    pub previous_edition: Option<Pubkey>,            // Previous election in the series
+    pub general_election: Option<Pubkey>,            // General election fed by this primary
+    pub promotion_rule: Option<PromotionRule>,       // Which primary candidates advance
+    pub candidates_promoted: bool,                   // Primary results carried over
+    pub pending_primaries: u16,                      // Linked primaries not yet promoted
+    pub closed_at: Option<i64>,                      // When finalized or cancelled
+    pub ballot_seed: Option<[u8; 32]>,               // Ballot order seed fixed at start
+    pub ballot_seed_slot: u64,                       // Slot whose SlotHashes entry seeded it
+    pub deposit_amount: u64,                         // Deposit per candidate (0 = none)
+    pub deposit_mint: Option<Pubkey>,                // Deposit token mint (None = SOL)
+    pub deposit_treasury: Pubkey,                    // Receives forfeited deposits
+    pub deposit_refund_bps: u16,                     // Vote share refunding a deposit (basis points)
+    pub deposited_candidates: u32,                   // Live candidates with a paid deposit
+    pub deposits_held: u32,                          // Deposits not yet settled
+    pub payer: Pubkey,                               // Rent payer
     pub bump: u8,                                    // PDA bump
 }
 
@@ -24,10 +68,53 @@ impl Election {
         4 + 500 + // description (String with max 500 chars)
         8 +  // start_time
         8 +  // end_time
+        8 +  // registration_start
+        8 +  // registration_end
+        1 +  // voter_roll_frozen
         1 +  // status
         8 +  // total_votes
+        8 +  // total_ballots
         4 +  // candidate_count
+        4 +  // next_candidate_id
+        4 +  // max_candidates
+        1 +  // withdrawal_policy
+        2 +  // district_count
+        2 +  // contest_count
+        2 +  // party_count
+        4 +  // registration_count
+        4 +  // nomination_count
+        2 +  // party_list_seats
+        1 +  // seat_allocation
+        8 +  // party_votes
+        8 +  // party_ballots
+        1 +  // seats_allocated
         1 +  // voter_registration_type
+        VoteWeighting::SIZE + // vote_weighting
+        1 +  // auto_start
+        8 +  // crank_bounty
+        2 +  // extension_count
+        8 +  // total_extension
+        8 +  // max_total_extension
+        1 + 1 + // last_extension_reason (Option<ExtensionReason>)
+        1 +  // is_paused
+        1 + 8 + // paused_at (Option<i64>)
+        1 + 32 + // cloned_from (Option<Pubkey>)
+        1 + 32 + // series (Option<Pubkey>)
+        1 + 32 + // previous_edition (Option<Pubkey>)
+        1 + 32 + // general_election (Option<Pubkey>)
+        1 + PromotionRule::SIZE + // promotion_rule (Option<PromotionRule>)
+        1 +  // candidates_promoted
+        2 +  // pending_primaries
+        1 + 8 + // closed_at (Option<i64>)
+        1 + 32 + // ballot_seed (Option<[u8; 32]>)
+        8 +  // ballot_seed_slot
+        8 +  // deposit_amount
+        1 + 32 + // deposit_mint (Option<Pubkey>)
+        32 + // deposit_treasury
+        2 +  // deposit_refund_bps
+        4 +  // deposited_candidates
+        4 +  // deposits_held
+        32 + // payer
         1;   // bump
 
     pub fn is_active(&self) -> bool {
@@ -40,7 +127,219 @@ impl Election {
             && current_time <= self.end_time
     }
 
+    // Latest end_time the remaining extension allowance can reach
+    pub fn latest_end_time(&self) -> i64 {
+        self.end_time
+            .saturating_add(self.max_total_extension.saturating_sub(self.total_extension))
+    }
+
+    // Part of the current pause that fell inside the voting window
+    pub fn pause_credit(&self, current_time: i64) -> i64 {
+        self.paused_at
+            .map(|paused_at| {
+                let from = paused_at.max(self.start_time);
+                let until = current_time.min(self.end_time);
+                until.saturating_sub(from).max(0)
+            })
+            .unwrap_or(0)
+    }
+
+    // Moves end_time `extension` seconds later and records why. Every
+    // extension, pause credits included, counts towards max_total_extension;
+    // returns false and changes nothing when the cap would be exceeded.
+    pub fn apply_extension(&mut self, extension: i64, reason: ExtensionReason) -> bool {
+        let total_extension = match self.total_extension.checked_add(extension) {
+            Some(total) if total <= self.max_total_extension => total,
+            _ => return false,
+        };
+        self.end_time = self.end_time.saturating_add(extension);
+        self.total_extension = total_extension;
+        self.extension_count = self.extension_count.saturating_add(1);
+        self.last_extension_reason = Some(reason);
+        true
+    }
+
     pub fn can_be_modified(&self) -> bool {
         self.status == ElectionStatus::Draft
     }
-}
\ No newline at end of file
+
+    pub fn is_registration_closed(&self, current_time: i64) -> bool {
+        current_time > self.registration_end
+            || matches!(
+                self.status,
+                ElectionStatus::Ended | ElectionStatus::Cancelled | ElectionStatus::Finalized
+            )
+    }
+
+    pub fn can_add_candidate(&self) -> bool {
+        self.candidate_count < self.max_candidates
+    }
+
+    // Votes for an inactive candidate were removed from the tallies
+    pub fn votes_voided_for(&self, candidate: &Candidate) -> bool {
+        !candidate.is_active() && self.withdrawal_policy.voids_votes()
+    }
+
+    // Candidates are displayed in ascending order of
+    // sha256(ballot_seed || candidate_id as u32 little-endian)
+    pub fn ballot_sort_key(&self, candidate_id: u32) -> Option<[u8; 32]> {
+        self.ballot_seed
+            .map(|seed| hashv(&[&seed, &candidate_id.to_le_bytes()]).to_bytes())
+    }
+
+    // Display order of the given candidate IDs; None before the election starts
+    pub fn ballot_order(&self, candidate_ids: &[u32]) -> Option<Vec<u32>> {
+        let mut keyed = candidate_ids
+            .iter()
+            .map(|id| self.ballot_sort_key(*id).map(|key| (key, *id)))
+            .collect::<Option<Vec<_>>>()?;
+        keyed.sort();
+        Some(keyed.into_iter().map(|(_, id)| id).collect())
+    }
+
+    pub fn requires_deposit(&self) -> bool {
+        self.deposit_amount > 0
+    }
+
+    // Every live candidate has paid its deposit
+    pub fn deposits_complete(&self) -> bool {
+        !self.requires_deposit() || self.deposited_candidates >= self.candidate_count
+    }
+
+    pub fn has_party_list(&self) -> bool {
+        self.party_list_seats > 0
+    }
+
+    pub fn has_pending_primaries(&self) -> bool {
+        self.pending_primaries > 0
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.status,
+            ElectionStatus::Cancelled | ElectionStatus::Finalized
+        )
+    }
+
+    pub fn is_valid_contest(&self, contest_id: Option<u16>) -> bool {
+        contest_id.is_none_or(|id| id < self.contest_count)
+    }
+
+    pub fn has_districts(&self) -> bool {
+        self.district_count > 0
+    }
+
+    // Districted elections require every candidate and voter to belong to
+    // exactly one existing district
+    pub fn is_valid_district(&self, district_id: Option<u16>) -> bool {
+        match district_id {
+            Some(id) => id < self.district_count,
+            None => !self.has_districts(),
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Draft election with every field zeroed except the ballot seed
+    fn election(ballot_seed: Option<[u8; 32]>) -> Election {
+        let mut election = Election::deserialize(&mut &vec![0u8; Election::SIZE][..]).unwrap();
+        election.ballot_seed = ballot_seed;
+        election
+    }
+
+    fn window(start_time: i64, end_time: i64, max_total_extension: i64) -> Election {
+        let mut election = election(None);
+        election.start_time = start_time;
+        election.end_time = end_time;
+        election.max_total_extension = max_total_extension;
+        election
+    }
+
+    #[test]
+    fn pause_credit_covers_only_the_voting_window() {
+        let mut election = window(100, 200, 1_000);
+        assert_eq!(election.pause_credit(150), 0);
+
+        election.paused_at = Some(50);
+        assert_eq!(election.pause_credit(80), 0);
+        assert_eq!(election.pause_credit(150), 50);
+        assert_eq!(election.pause_credit(500), 100);
+
+        election.paused_at = Some(120);
+        assert_eq!(election.pause_credit(150), 30);
+        assert_eq!(election.pause_credit(300), 80);
+    }
+
+    #[test]
+    fn extensions_are_recorded_with_their_reason() {
+        let mut election = window(100, 200, 60);
+
+        assert!(election.apply_extension(20, ExtensionReason::Outage));
+        assert!(election.apply_extension(30, ExtensionReason::PauseCredit));
+        assert_eq!(election.end_time, 250);
+        assert_eq!(election.total_extension, 50);
+        assert_eq!(election.extension_count, 2);
+        assert_eq!(election.last_extension_reason, Some(ExtensionReason::PauseCredit));
+    }
+
+    #[test]
+    fn pause_credits_count_towards_the_extension_cap() {
+        let mut election = window(100, 200, 60);
+        assert!(election.apply_extension(40, ExtensionReason::Other));
+
+        assert!(!election.apply_extension(21, ExtensionReason::PauseCredit));
+        assert_eq!(election.end_time, 240);
+        assert_eq!(election.total_extension, 40);
+        assert_eq!(election.extension_count, 1);
+        assert_eq!(election.last_extension_reason, Some(ExtensionReason::Other));
+
+        assert!(election.apply_extension(20, ExtensionReason::PauseCredit));
+        assert_eq!(election.latest_end_time(), election.end_time);
+    }
+
+    #[test]
+    fn no_order_before_the_seed_is_set() {
+        let election = election(None);
+        assert_eq!(election.ballot_sort_key(0), None);
+        assert_eq!(election.ballot_order(&[0, 1, 2]), None);
+    }
+
+    #[test]
+    fn sort_key_hashes_seed_and_little_endian_id() {
+        let seed = [7u8; 32];
+        let election = election(Some(seed));
+        let expected = hashv(&[&seed, &[5u8, 0, 0, 0]]).to_bytes();
+        assert_eq!(election.ballot_sort_key(5), Some(expected));
+    }
+
+    #[test]
+    fn order_is_a_permutation_sorted_by_key() {
+        let election = election(Some([1u8; 32]));
+        let ids: Vec<u32> = (0..20).collect();
+        let order = election.ballot_order(&ids).unwrap();
+
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, ids);
+
+        let keys: Vec<[u8; 32]> = order
+            .iter()
+            .map(|id| election.ballot_sort_key(*id).unwrap())
+            .collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn order_ignores_input_order_and_depends_on_seed() {
+        let first = election(Some([1u8; 32]));
+        let second = election(Some([2u8; 32]));
+        let ids: Vec<u32> = (0..20).collect();
+        let reversed: Vec<u32> = ids.iter().rev().copied().collect();
+
+        assert_eq!(first.ballot_order(&ids), first.ballot_order(&reversed));
+        assert_ne!(first.ballot_order(&ids), second.ballot_order(&ids));
+        assert_eq!(first.ballot_order(&[]), Some(vec![]));
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ElectionVault {
+    pub election: Pubkey,          // Election this vault funds
+    pub authority: Pubkey,         // Election creator (may top up / withdraw)
+    pub total_reimbursed: u64,     // Lamports paid out to vote payers
+    pub bump: u8,                  // PDA bump
+}
+
+impl ElectionVault {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        32 + // authority
+        8 +  // total_reimbursed
+        1;   // bump
+}
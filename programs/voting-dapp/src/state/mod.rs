@@ -3,15 +3,35 @@ use anchor_lang::prelude::*;
 pub mod admin;
 pub mod admin_permissions;
 pub mod admin_registry;
+pub mod archived_election;
 pub mod candidate;
+pub mod candidate_deposit;
+pub mod contest;
+pub mod delegation;
+pub mod district;
 pub mod election;
+pub mod election_vault;
+pub mod nomination;
+pub mod party;
+pub mod series;
+pub mod vote_escrow;
 pub mod vote_record;
 
 pub use admin::*;
 pub use admin_permissions::*;
 pub use admin_registry::*;
+pub use archived_election::*;
 pub use candidate::*;
+pub use candidate_deposit::*;
+pub use contest::*;
+pub use delegation::*;
+pub use district::*;
 pub use election::*;
+pub use election_vault::*;
+pub use nomination::*;
+pub use party::*;
+pub use series::*;
+pub use vote_escrow::*;
 pub use vote_record::*;
 
 // ENUMS
@@ -30,6 +50,85 @@ pub enum VoterRegistrationType {
     Whitelist,  // Only approved voters can vote
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VoteWeighting {
+    Standard,                    // Registration weight (1 without registration)
+    VoteEscrow { mint: Pubkey }, // Voter's decaying escrow power for this mint
+}
+
+impl VoteWeighting {
+    pub const SIZE: usize = 1 + 32; // variant + largest payload (mint)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BallotType {
+    SingleChoice, // Exactly one candidate
+    MultiChoice,  // Up to one candidate per seat
+    Approval,     // Any number of candidates (up to MAX_CONTEST_CHOICES)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PromotionRule {
+    TopN { count: u8 },               // The `count` main-race candidates with most votes
+    MinimumShare { basis_points: u16 }, // Candidates with at least this share of the vote
+}
+
+impl PromotionRule {
+    pub const SIZE: usize = 1 + 2; // variant + largest payload (basis_points)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExtensionReason {
+    Outage,          // Network or infrastructure outage
+    ClientIssue,     // Wallet or front-end problems blocked voters
+    LegalOrder,      // Extension ordered by an external authority
+    Other,           // Any other reason
+    PauseCredit,     // Voting time lost to a pause, added back on resume
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CandidateStatus {
+    Active,        // Accepting votes
+    Withdrawn,     // Dropped out (signed by the candidate)
+    Disqualified,  // Disqualified by an admin
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawalPolicy {
+    KeepVotes,    // Existing votes stay counted
+    VoidVotes,    // Existing votes are removed from the tallies
+    AllowRecast,  // Existing votes are removed; affected voters may vote again
+}
+
+impl WithdrawalPolicy {
+    pub fn voids_votes(&self) -> bool {
+        *self != WithdrawalPolicy::KeepVotes
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SeatAllocationMethod {
+    DHondt,       // Divisors 1, 2, 3, ...
+    SainteLague,  // Divisors 1, 3, 5, ...
+}
+
+impl SeatAllocationMethod {
+    // Divisor applied to a party's votes once it holds `seats` seats
+    pub fn divisor(&self, seats: u16) -> u64 {
+        match self {
+            SeatAllocationMethod::DHondt => seats as u64 + 1,
+            SeatAllocationMethod::SainteLague => 2 * seats as u64 + 1,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum NominationStatus {
+    Pending,   // Waiting for admin review
+    Approved,  // Candidate created
+    Rejected,  // Rejected by admin
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum RegistrationStatus {
     Pending,   // Waiting for approval
@@ -47,6 +146,9 @@ pub struct VoterRegistration {
     pub requested_at: i64,             // Request timestamp
     pub approved_at: Option<i64>,      // Approval timestamp
     pub approved_by: Option<Pubkey>,   // Admin who approved
+    pub weight: u64,                   // Voting share assigned by admin
+    pub district_id: Option<u16>,      // Voter's district
+    pub payer: Pubkey,                 // Rent payer
     pub bump: u8,                      // PDA bump
 }
 
@@ -58,5 +160,8 @@ impl VoterRegistration {
         8 +  // requested_at
         1 + 8 + // approved_at (Option<i64>)
         1 + 32 + // approved_by (Option<Pubkey>)
+        8 +  // weight
+        1 + 2 +  // district_id (Option<u16>)
+        32 + // payer
         1;   // bump
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use super::NominationStatus;
+
+#[account]
+pub struct Nomination {
+    pub election: Pubkey,              // Election
+    pub nominee: Pubkey,               // Would-be candidate's wallet (signed)
+    pub nominator: Option<Pubkey>,     // Co-signing nominator
+    pub name: String,                  // Candidate name
+    pub description: String,           // Candidate description
+    pub image_url: String,             // Candidate image URL
+    pub district_id: Option<u16>,      // Requested district
+    pub contest_id: Option<u16>,       // Requested contest (None = main race)
+    pub status: NominationStatus,      // Nomination status
+    pub submitted_at: i64,             // Submission timestamp
+    pub reviewed_at: Option<i64>,      // Approval or rejection timestamp
+    pub reviewed_by: Option<Pubkey>,   // Admin who reviewed
+    pub candidate: Option<Pubkey>,     // Candidate created on approval
+    pub bump: u8,                      // PDA bump
+}
+
+impl Nomination {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        32 + // nominee
+        1 + 32 + // nominator (Option<Pubkey>)
+        4 + 50 + // name (String with max 50 chars)
+        4 + 200 + // description (String with max 200 chars)
+        4 + 200 + // image_url (String with max 200 chars)
+        1 + 2 +  // district_id (Option<u16>)
+        1 + 2 +  // contest_id (Option<u16>)
+        1 +  // status
+        8 +  // submitted_at
+        1 + 8 + // reviewed_at (Option<i64>)
+        1 + 32 + // reviewed_by (Option<Pubkey>)
+        1 + 32 + // candidate (Option<Pubkey>)
+        1;   // bump
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Party {
+    pub election: Pubkey,      // Election
+    pub party_id: u16,         // Index within the election
+    pub name: String,          // Party name
+    pub list_size: u16,        // Candidates on the party list
+    pub active_list_size: u16, // Listed candidates still active (seat cap)
+    pub vote_count: u64,       // Vote weight from party-list ballots
+    pub seats_won: u16,        // Seats allocated at finalization
+    pub seats_filled: u16,     // Seats assigned to list candidates
+    pub seats_assigned: bool,  // List candidates marked elected
+    pub payer: Pubkey,         // Rent payer
+    pub bump: u8,              // PDA bump
+}
+
+impl Party {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        2 +  // party_id
+        4 + 50 + // name (String with max 50 chars)
+        2 +  // list_size
+        2 +  // active_list_size
+        8 +  // vote_count
+        2 +  // seats_won
+        2 +  // seats_filled
+        1 +  // seats_assigned
+        32 + // payer
+        1;   // bump
+}
+
+#[account]
+pub struct PartyVoteRecord {
+    pub election: Pubkey,      // Election
+    pub voter: Pubkey,         // Voter wallet
+    pub party: Pubkey,         // Party chosen
+    pub weight: u64,           // Voting power applied
+    pub payer: Pubkey,         // Rent payer (fee payer or election vault)
+    pub voted_at: i64,         // Vote timestamp
+    pub bump: u8,              // PDA bump
+}
+
+impl PartyVoteRecord {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // election
+        32 + // voter
+        32 + // party
+        8 +  // weight
+        32 + // payer
+        8 +  // voted_at
+        1;   // bump
+}
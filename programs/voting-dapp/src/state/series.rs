@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use super::{VoteWeighting, VoterRegistrationType};
+
+#[account]
+pub struct Series {
+    pub authority: Pubkey,                           // Admin who created
+    pub series_id: u64,                              // ID chosen by the authority
+    pub name: String,                                // Series name (edition titles)
+    pub description: String,                         // Description copied to editions
+    pub voting_duration: i64,                        // Default voting window (seconds)
+    pub voter_registration_type: VoterRegistrationType, // Default registration type
+    pub vote_weighting: VoteWeighting,               // Default vote weighting
+    pub max_total_extension: i64,                    // Default extension cap
+    pub edition_count: u32,                          // Editions created
+    pub latest_election: Option<Pubkey>,             // Most recent edition
+    pub recorded_editions: u32,                      // Editions with recorded results
+    pub total_votes: u64,                            // Vote weight across recorded editions
+    pub total_ballots: u64,                          // Turnout across recorded editions
+    pub bump: u8,                                    // PDA bump
+}
+
+impl Series {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // authority
+        8 +  // series_id
+        4 + 50 + // name (String with max 50 chars)
+        4 + 500 + // description (String with max 500 chars)
+        8 +  // voting_duration
+        1 +  // voter_registration_type
+        VoteWeighting::SIZE + // vote_weighting
+        8 +  // max_total_extension
+        4 +  // edition_count
+        1 + 32 + // latest_election (Option<Pubkey>)
+        4 +  // recorded_editions
+        8 +  // total_votes
+        8 +  // total_ballots
+        1;   // bump
+}
+
+#[account]
+pub struct SeriesEdition {
+    pub series: Pubkey,                    // Series
+    pub edition: u32,                      // Index within the series
+    pub election: Pubkey,                  // Election of this edition
+    pub previous_election: Option<Pubkey>, // Election of the previous edition
+    pub results_recorded: bool,            // Results copied from the election
+    pub total_votes: u64,                  // Vote weight cast
+    pub total_ballots: u64,                // Voters who voted
+    pub winner: Option<Pubkey>,            // Top main-race candidate (None on tie)
+    pub winner_votes: u64,                 // Winner's vote count
+    pub bump: u8,                          // PDA bump
+}
+
+impl SeriesEdition {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // series
+        4 +  // edition
+        32 + // election
+        1 + 32 + // previous_election (Option<Pubkey>)
+        1 +  // results_recorded
+        8 +  // total_votes
+        8 +  // total_ballots
+        1 + 32 + // winner (Option<Pubkey>)
+        8 +  // winner_votes
+        1;   // bump
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_LOCK_DURATION;
+
+#[account]
+pub struct VoteEscrow {
+    pub owner: Pubkey,         // Wallet that locked the tokens
+    pub mint: Pubkey,          // Locked token mint
+    pub amount: u64,           // Tokens held in the escrow vault
+    pub locked_at: i64,        // Lock timestamp
+    pub lock_end: i64,         // Tokens withdrawable from this time
+    pub bump: u8,              // PDA bump
+    pub vault_bump: u8,        // Escrow vault PDA bump
+}
+
+impl VoteEscrow {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // owner
+        32 + // mint
+        8 +  // amount
+        8 +  // locked_at
+        8 +  // lock_end
+        1 +  // bump
+        1;   // vault_bump
+
+    pub fn is_locked(&self, current_time: i64) -> bool {
+        current_time < self.lock_end
+    }
+
+    // Voting power decays linearly from `amount` (at the maximum lock
+    // duration) down to zero at `lock_end`.
+    pub fn voting_power(&self, current_time: i64) -> u64 {
+        if !self.is_locked(current_time) {
+            return 0;
+        }
+
+        let remaining = (self.lock_end - current_time).min(MAX_LOCK_DURATION) as u128;
+        (self.amount as u128 * remaining / MAX_LOCK_DURATION as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escrow(amount: u64, lock_end: i64) -> VoteEscrow {
+        VoteEscrow {
+            owner: Pubkey::default(),
+            mint: Pubkey::default(),
+            amount,
+            locked_at: 0,
+            lock_end,
+            bump: 0,
+            vault_bump: 0,
+        }
+    }
+
+    #[test]
+    fn full_power_at_maximum_lock() {
+        assert_eq!(escrow(1_000, MAX_LOCK_DURATION).voting_power(0), 1_000);
+    }
+
+    #[test]
+    fn power_decays_linearly() {
+        let escrow = escrow(1_000, MAX_LOCK_DURATION);
+        assert_eq!(escrow.voting_power(MAX_LOCK_DURATION / 2), 500);
+        assert_eq!(escrow.voting_power(MAX_LOCK_DURATION / 4 * 3), 250);
+    }
+
+    #[test]
+    fn lock_beyond_maximum_is_capped() {
+        assert_eq!(escrow(1_000, 3 * MAX_LOCK_DURATION).voting_power(0), 1_000);
+    }
+
+    #[test]
+    fn no_power_once_unlocked() {
+        let escrow = escrow(1_000, 100);
+        assert_eq!(escrow.voting_power(100), 0);
+        assert_eq!(escrow.voting_power(101), 0);
+    }
+
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        assert_eq!(escrow(u64::MAX, MAX_LOCK_DURATION).voting_power(0), u64::MAX);
+    }
+}
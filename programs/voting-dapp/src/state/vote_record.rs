@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 
 #[account]
 pub struct VoteRecord {
     pub election: Pubkey,      // Election
     pub voter: Pubkey,         // Voter wallet
-    pub candidate: Pubkey,     // Candidate voted for
-    pub voted_at: i64,         // Vote timestamp
+    pub candidate: Pubkey,     // Candidate voted for (default when delegated)
+    pub weight: u64,           // Voting power applied to the tally
+    pub cast_by: Pubkey,       // Voter, or the delegate whose vote this follows
+    pub delegated_weight: u64, // Power of delegators following this vote
+    pub payer: Pubkey,         // Rent payer (fee payer or election vault)
+    pub voted_at: i64,         // Vote timestamp (last change)
+    pub revision: u32,         // Times the vote was changed
     pub bump: u8,              // PDA bump
 }
 
@@ -14,6 +20,32 @@ impl VoteRecord {
         32 + // election
         32 + // voter
         32 + // candidate
+        8 +  // weight
+        32 + // cast_by
+        8 +  // delegated_weight
+        32 + // payer
         8 +  // voted_at
+        4 +  // revision
         1;   // bump
+
+    pub fn is_delegated(&self) -> bool {
+        self.cast_by != self.voter
+    }
+
+    // Merkle leaf written to the election archive when the record is closed:
+    // sha256(voter || candidate || weight || delegated_weight || cast_by ||
+    // voted_at || revision), integers little-endian. A delegated leaf has no
+    // candidate; it counts for whatever `cast_by`'s leaf chose.
+    pub fn archive_leaf(&self) -> [u8; 32] {
+        hashv(&[
+            self.voter.as_ref(),
+            self.candidate.as_ref(),
+            &self.weight.to_le_bytes(),
+            &self.delegated_weight.to_le_bytes(),
+            self.cast_by.as_ref(),
+            &self.voted_at.to_le_bytes(),
+            &self.revision.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
 }
\ No newline at end of file
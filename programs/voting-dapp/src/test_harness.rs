@@ -0,0 +1,280 @@
+//! In-process runner for instruction tests.
+//!
+//! Accounts are serialized into the loader's input layout and passed to the
+//! program entrypoint, with Clock and Rent served by syscall stubs. Account
+//! changes are only kept when the instruction succeeds, like a transaction.
+//!
+//! Off-chain builds cannot perform CPIs, so instructions that create
+//! accounts cannot run here; tests write those accounts as fixtures instead.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{deserialize, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER};
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+use crate::constants::*;
+use crate::errors::VotingError;
+use crate::state::*;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(0) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW.with(Cell::get),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+}
+
+#[derive(Clone, Default)]
+struct TestAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+/// Accounts shared between the instructions of one test.
+pub struct TestLedger {
+    accounts: HashMap<Pubkey, TestAccount>,
+}
+
+impl TestLedger {
+    pub fn new(unix_timestamp: i64) -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+        });
+        NOW.with(|now| now.set(unix_timestamp));
+
+        Self {
+            accounts: HashMap::new(),
+        }
+    }
+
+    pub fn fund(&mut self, key: Pubkey, lamports: u64) {
+        self.accounts.entry(key).or_default().lamports += lamports;
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    /// Accounts the runtime would still hold after the transaction.
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.lamports(key) > 0
+    }
+
+    /// Drops an account, as if it had been closed by another transaction.
+    pub fn remove(&mut self, key: &Pubkey) {
+        self.accounts.remove(key);
+    }
+
+    /// Writes a rent-exempt program account of `space` bytes.
+    pub fn set_account<T: AccountSerialize>(&mut self, key: Pubkey, account: &T, space: usize) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "account does not fit its space");
+        data.resize(space, 0);
+
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(space),
+                data,
+                owner: crate::ID,
+            },
+        );
+    }
+
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account does not exist");
+        assert_eq!(account.owner, crate::ID, "account is not owned by the program");
+        T::try_deserialize(&mut &account.data[..]).expect("account does not deserialize")
+    }
+
+    pub fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+        remaining_accounts: &[AccountMeta],
+    ) -> std::result::Result<(), ProgramError> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend_from_slice(remaining_accounts);
+        let data = args.data();
+
+        let mut input = self.serialize(&metas, &data);
+        let (program_id, infos, instruction_data) = unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+        crate::entry(program_id, &infos, instruction_data)?;
+
+        for info in infos.iter() {
+            let account = TestAccount {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+            };
+            if account.lamports == 0 {
+                self.accounts.remove(info.key);
+            } else {
+                self.accounts.insert(*info.key, account);
+            }
+        }
+        Ok(())
+    }
+
+    /// Lays out the accounts, instruction data and program id the way the
+    /// loader does, in an 8-byte aligned buffer.
+    fn serialize(&self, metas: &[AccountMeta], data: &[u8]) -> Vec<u64> {
+        let mut bytes: Vec<u8> = Vec::new();
+        bytes.extend_from_slice(&(metas.len() as u64).to_le_bytes());
+
+        for (index, meta) in metas.iter().enumerate() {
+            if let Some(first) = metas[..index].iter().position(|other| other.pubkey == meta.pubkey) {
+                bytes.push(first as u8);
+                bytes.extend_from_slice(&[0; 7]);
+                continue;
+            }
+            // Flags are merged across duplicates, as in a transaction message
+            let is_signer = metas.iter().any(|other| other.pubkey == meta.pubkey && other.is_signer);
+            let is_writable = metas.iter().any(|other| other.pubkey == meta.pubkey && other.is_writable);
+            let account = self.accounts.get(&meta.pubkey).cloned().unwrap_or_default();
+
+            bytes.push(NON_DUP_MARKER);
+            bytes.push(is_signer as u8);
+            bytes.push(is_writable as u8);
+            bytes.push(0); // executable
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(meta.pubkey.as_ref());
+            bytes.extend_from_slice(account.owner.as_ref());
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(8), 0);
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes.extend_from_slice(crate::ID.as_ref());
+
+        let mut input = vec![0u64; bytes.len().div_ceil(8)];
+        for (word, chunk) in input.iter_mut().zip(bytes.chunks(8)) {
+            let mut buffer = [0u8; 8];
+            buffer[..chunk.len()].copy_from_slice(chunk);
+            *word = u64::from_le_bytes(buffer);
+        }
+        input
+    }
+}
+
+pub fn writable(key: Pubkey) -> AccountMeta {
+    AccountMeta::new(key, false)
+}
+
+/// The error an instruction returns for `code`.
+pub fn error(code: VotingError) -> ProgramError {
+    anchor_lang::error::Error::from(code).into()
+}
+
+/// Address of one of the program's PDAs.
+pub fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+
+/// Admin registry, plus the super admin as a full-permission admin.
+pub fn setup_admin(ledger: &mut TestLedger) {
+    let (admin_registry, registry_bump) = pda(&[ADMIN_REGISTRY_SEED]);
+    let (admin_account, admin_bump) = pda(&[ADMIN_SEED, SUPER_ADMIN.as_ref()]);
+
+    ledger.fund(SUPER_ADMIN, 1_000_000_000);
+    ledger.set_account(
+        admin_registry,
+        &AdminRegistry {
+            super_admin: SUPER_ADMIN,
+            election_count: 0,
+            deleted_election_count: 0,
+            admin_count: 1,
+            paused: false,
+            bump: registry_bump,
+        },
+        AdminRegistry::SIZE,
+    );
+    ledger.set_account(
+        admin_account,
+        &Admin {
+            authority: SUPER_ADMIN,
+            name: "Admin".to_string(),
+            permissions: AdminPermissions::full_permissions(),
+            added_by: SUPER_ADMIN,
+            added_at: 0,
+            is_active: true,
+            bump: admin_bump,
+        },
+        Admin::SIZE,
+    );
+}
+
+/// Draft election owned by the super admin, with every count at zero.
+pub fn draft_election(election_id: u64) -> (Pubkey, Election) {
+    let (key, bump) = pda(&[ELECTION_SEED, &election_id.to_le_bytes()]);
+    let mut election = Election::deserialize(&mut &vec![0u8; Election::SIZE][..]).unwrap();
+    election.election_id = election_id;
+    election.authority = SUPER_ADMIN;
+    election.payer = SUPER_ADMIN;
+    election.title = "Board election".to_string();
+    election.bump = bump;
+    (key, election)
+}
+
+/// Active main-race candidate paid for by the super admin.
+pub fn active_candidate(election: Pubkey, candidate_id: u32) -> (Pubkey, Candidate) {
+    let (key, bump) = pda(&[CANDIDATE_SEED, election.as_ref(), &candidate_id.to_le_bytes()]);
+    let candidate = Candidate {
+        election,
+        candidate_id,
+        name: format!("Candidate {candidate_id}"),
+        description: String::new(),
+        image_url: String::new(),
+        metadata_uri: None,
+        metadata_hash: None,
+        district_id: None,
+        contest_id: None,
+        party_id: None,
+        list_position: 0,
+        source_candidate: None,
+        wallet: None,
+        vote_count: 0,
+        elected: false,
+        deposit_paid: false,
+        status: CandidateStatus::Active,
+        status_changed_at: None,
+        voided_votes: 0,
+        added_by: SUPER_ADMIN,
+        added_at: 0,
+        last_updated_at: 0,
+        payer: SUPER_ADMIN,
+        bump,
+    };
+    (key, candidate)
+}
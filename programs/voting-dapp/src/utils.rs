@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+// Creates a program-owned PDA account from within an instruction, for
+// accounts that are only known at runtime (e.g. passed as remaining accounts).
+// Mirrors Anchor's `init`: an account that was pre-funded is topped up,
+// allocated and assigned instead of created.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = new_account.lamports();
+
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent_lamports,
+            space as u64,
+            program_id,
+        )?;
+    } else {
+        let shortfall = rent_lamports.saturating_sub(current_lamports);
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: new_account.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            program_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Closes a program-owned account that was loaded manually, sending its
+// lamports to `recipient`. Mirrors Anchor's `close` constraint.
+pub fn close_account<'info>(info: &AccountInfo<'info>, recipient: &AccountInfo<'info>) -> Result<()> {
+    let recipient_lamports = recipient.lamports();
+    **recipient.lamports.borrow_mut() = recipient_lamports
+        .checked_add(info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **info.lamports.borrow_mut() = 0;
+
+    info.assign(&system_program::ID);
+    info.resize(0)?;
+    Ok(())
+}
+
+// Serializes an account (discriminator included) into a freshly created account.
+pub fn write_account<T: AccountSerialize>(account: &T, info: &AccountInfo) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
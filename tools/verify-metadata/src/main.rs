@@ -0,0 +1,222 @@
+// Verifies a candidate metadata document against the hash stored on chain.
+//
+// Usage: verify-metadata <file path or http(s) URL> <metadata_hash as hex>
+//
+// The document's bytes must hash (sha256) to the expected value and the
+// document must follow candidate-metadata.schema.json.
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+// Largest document the tool will download
+const MAX_DOCUMENT_SIZE: u64 = 1024 * 1024;
+
+// maxLength of "name" in candidate-metadata.schema.json, counted in
+// characters like JSON Schema does
+const MAX_NAME_LENGTH: usize = 50;
+
+const SCHEMA_VERSION: u64 = 1;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: verify-metadata <file-or-url> <sha256-hex>");
+        return ExitCode::from(2);
+    }
+
+    match verify(&args[1], &args[2]) {
+        Ok(name) => {
+            println!("OK: metadata for \"{}\" matches the on-chain hash", name);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("FAILED: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn verify(source: &str, expected_hash: &str) -> Result<String, String> {
+    let expected = parse_hash(expected_hash)?;
+    let document = fetch(source)?;
+
+    let actual: [u8; 32] = Sha256::digest(&document).into();
+    if actual != expected {
+        return Err(format!(
+            "hash mismatch: expected {}, got {}",
+            hex::encode(expected),
+            hex::encode(actual)
+        ));
+    }
+
+    let json: Value = serde_json::from_slice(&document)
+        .map_err(|error| format!("document is not valid JSON: {}", error))?;
+    validate(&json)
+}
+
+fn parse_hash(hash: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(hash.trim().trim_start_matches("0x"))
+        .map_err(|error| format!("invalid hash: {}", error))?;
+    bytes
+        .try_into()
+        .map_err(|_| "invalid hash: expected 32 bytes".to_string())
+}
+
+// Reads the document from an http(s) URL or a local file
+fn fetch(source: &str) -> Result<Vec<u8>, String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let response = ureq::get(source)
+            .call()
+            .map_err(|error| format!("failed to fetch {}: {}", source, error))?;
+
+        let mut document = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_DOCUMENT_SIZE + 1)
+            .read_to_end(&mut document)
+            .map_err(|error| format!("failed to read {}: {}", source, error))?;
+        if document.len() as u64 > MAX_DOCUMENT_SIZE {
+            return Err(format!("document larger than {} bytes", MAX_DOCUMENT_SIZE));
+        }
+        Ok(document)
+    } else {
+        let path = source.strip_prefix("file://").unwrap_or(source);
+        std::fs::read(path).map_err(|error| format!("failed to read {}: {}", path, error))
+    }
+}
+
+// Checks the rules of candidate-metadata.schema.json; returns the name
+fn validate(json: &Value) -> Result<String, String> {
+    let object = json
+        .as_object()
+        .ok_or("document must be a JSON object")?;
+
+    for key in object.keys() {
+        if !["version", "name", "summary", "manifesto", "image", "links"].contains(&key.as_str()) {
+            return Err(format!("unknown field \"{}\"", key));
+        }
+    }
+
+    match object.get("version").and_then(Value::as_u64) {
+        Some(SCHEMA_VERSION) => {}
+        Some(version) => return Err(format!("unsupported version {}", version)),
+        None => return Err("\"version\" must be a number".to_string()),
+    }
+
+    let name = optional_string(object, "name")?.ok_or("\"name\" is required")?;
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("\"name\" must be 1 to {} characters", MAX_NAME_LENGTH));
+    }
+
+    optional_string(object, "summary")?;
+    optional_string(object, "manifesto")?;
+    optional_string(object, "image")?;
+
+    if let Some(links) = object.get("links") {
+        let links = links.as_array().ok_or("\"links\" must be an array")?;
+        for link in links {
+            let link = link.as_object().ok_or("each link must be an object")?;
+            if link.len() != 2 {
+                return Err("each link must have exactly \"label\" and \"url\"".to_string());
+            }
+            for key in ["label", "url"] {
+                match optional_string(link, key)? {
+                    Some(value) if !value.is_empty() => {}
+                    _ => return Err(format!("link \"{}\" is required", key)),
+                }
+            }
+        }
+    }
+
+    Ok(name.to_string())
+}
+
+fn optional_string<'a>(object: &'a Map<String, Value>, key: &str) -> Result<Option<&'a str>, String> {
+    match object.get(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(format!("\"{}\" must be a string", key)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn accepts_minimal_and_full_documents() {
+        assert_eq!(validate(&json!({ "version": 1, "name": "Alice" })), Ok("Alice".to_string()));
+
+        let full = json!({
+            "version": 1,
+            "name": "Alice",
+            "summary": "Short",
+            "manifesto": "Long",
+            "image": "https://example.com/alice.png",
+            "links": [{ "label": "Site", "url": "https://example.com" }]
+        });
+        assert_eq!(validate(&full), Ok("Alice".to_string()));
+    }
+
+    #[test]
+    fn counts_name_length_in_characters() {
+        let name = "é".repeat(MAX_NAME_LENGTH);
+        assert!(validate(&json!({ "version": 1, "name": name })).is_ok());
+
+        let name = "é".repeat(MAX_NAME_LENGTH + 1);
+        assert!(validate(&json!({ "version": 1, "name": name })).is_err());
+        assert!(validate(&json!({ "version": 1, "name": "" })).is_err());
+    }
+
+    #[test]
+    fn rejects_schema_violations() {
+        assert!(validate(&json!([])).is_err());
+        assert!(validate(&json!({ "name": "Alice" })).is_err());
+        assert!(validate(&json!({ "version": 2, "name": "Alice" })).is_err());
+        assert!(validate(&json!({ "version": 1 })).is_err());
+        assert!(validate(&json!({ "version": 1, "name": 7 })).is_err());
+        assert!(validate(&json!({ "version": 1, "name": "Alice", "extra": true })).is_err());
+        assert!(validate(&json!({ "version": 1, "name": "Alice", "summary": 1 })).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_links() {
+        for links in [
+            json!({}),
+            json!(["https://example.com"]),
+            json!([{ "label": "Site" }]),
+            json!([{ "label": "", "url": "https://example.com" }]),
+            json!([{ "label": "Site", "url": "https://example.com", "extra": 1 }]),
+        ] {
+            assert!(validate(&json!({ "version": 1, "name": "Alice", "links": links })).is_err());
+        }
+    }
+
+    #[test]
+    fn parses_hex_hashes() {
+        let hex = "ab".repeat(32);
+        assert_eq!(parse_hash(&hex), Ok([0xab; 32]));
+        assert_eq!(parse_hash(&format!(" 0x{} ", hex)), Ok([0xab; 32]));
+        assert!(parse_hash("abcd").is_err());
+        assert!(parse_hash(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn verifies_local_files_against_the_hash() {
+        let document = br#"{ "version": 1, "name": "Alice" }"#;
+        let path = std::env::temp_dir().join(format!("verify-metadata-{}.json", std::process::id()));
+        std::fs::write(&path, document).unwrap();
+        let source = path.to_str().unwrap();
+
+        let hash = hex::encode(Sha256::digest(document));
+        assert_eq!(verify(source, &hash), Ok("Alice".to_string()));
+        assert!(verify(&format!("file://{}", source), &hash).is_ok());
+        assert!(verify(source, &"00".repeat(32)).unwrap_err().starts_with("hash mismatch"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}